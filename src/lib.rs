@@ -7,7 +7,9 @@ pub trait NamedEnum {
 }
 
 pub trait Values {
-    fn values() -> Vec<Self> where Self: Sized;
+    fn values() -> Vec<Self>
+    where
+        Self: Sized;
 }
 
 pub trait Is<Rhs: ?Sized = Self> {
@@ -1,4 +1,3 @@
 pub mod sql;
 
-fn main() {
-}
+fn main() {}
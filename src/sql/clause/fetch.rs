@@ -0,0 +1,173 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    err::SyntaxError,
+    format::{fmt_sql, FormatOptions, ToSql},
+    frag::row_count::RowCount,
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+/// `FETCH {FIRST | NEXT} [n] {ROW | ROWS} {ONLY | WITH TIES}`
+///
+/// Without a count a single row is fetched. `FIRST` / `NEXT` and `ROW` / `ROWS` mean
+/// the same and are written as `FIRST` and `ROWS`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FetchClause<'a> {
+    pub count: Option<RowCount<'a>>,
+    pub with_ties: bool,
+    pub span: Span,
+}
+
+impl Named for FetchClause<'_> {
+    const NAMED: &'static str = "fetch clause";
+}
+
+impl Spanned for FetchClause<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for FetchClause<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        match (source.items.get(index), source.items.get(index + 1)) {
+            (Some(Lexer::FETCH(_)), Some(Lexer::FIRST(_) | Lexer::NEXT(_))) => {
+                let (count, count_end_index) = match source.items.get(index + 2) {
+                    Some(Lexer::ROW(_) | Lexer::ROWS(_)) => (None, index + 2),
+                    _ => {
+                        source.expect(index + 2, &["ROWS"]);
+                        let (count, end_index) = RowCount::parse(source, index + 2)?;
+                        (Some(count), end_index)
+                    }
+                };
+                match source.items.get(count_end_index) {
+                    Some(Lexer::ROW(_) | Lexer::ROWS(_)) => {}
+                    _ => return Err(source.missing(count_end_index, "ROWS", &["ROWS"])),
+                }
+                let (with_ties, end_index) = match (
+                    source.items.get(count_end_index + 1),
+                    source.items.get(count_end_index + 2),
+                ) {
+                    (Some(Lexer::ONLY(_)), _) => (false, count_end_index + 2),
+                    (Some(Lexer::WITH(_)), Some(Lexer::TIES(_))) => (true, count_end_index + 3),
+                    (Some(Lexer::WITH(_)), _) => {
+                        return Err(source.missing(count_end_index + 2, "TIES", &["TIES"]))
+                    }
+                    _ => {
+                        return Err(source.missing(count_end_index + 1, "ONLY", &["ONLY", "WITH"]))
+                    }
+                };
+                Ok((
+                    FetchClause {
+                        count,
+                        with_ties,
+                        span: source.span(index, end_index),
+                    },
+                    end_index,
+                ))
+            }
+            (Some(Lexer::FETCH(_)), _) => {
+                Err(source.missing(index + 1, "FIRST", &["FIRST", "NEXT"]))
+            }
+            _ => Err(source.missing(index, Self::NAMED, &["FETCH"])),
+        }
+    }
+}
+
+impl ToSql for FetchClause<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let mut sql = format!("{} {}", options.keyword("FETCH"), options.keyword("FIRST"));
+        if let Some(count) = &self.count {
+            sql.push(' ');
+            sql.push_str(&count.to_sql(options));
+        }
+        sql.push(' ');
+        sql.push_str(&options.keyword("ROWS"));
+        sql.push(' ');
+        match self.with_ties {
+            true => sql.push_str(&options.keyword("WITH TIES")),
+            false => sql.push_str(&options.keyword("ONLY")),
+        }
+        sql
+    }
+}
+
+impl fmt::Display for FetchClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::{
+        expr::param::ParamExpr,
+        frag::row_count::RowCount,
+        lexer::pattern::LexerPattern,
+        parser::{parse_stmt, LexerParser, SyntaxPattern},
+        span::eq_ignore_span,
+    };
+
+    use super::FetchClause;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(FetchClause::NAMED, "fetch clause");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            FetchClause::parse(&source, 0),
+            Err(err) if err.cause == "missing fetch clause"
+        ));
+        // incomplete clauses
+        for (text, cause) in [
+            ("FETCH 10 ROWS ONLY", "missing FIRST"),
+            ("FETCH FIRST 10", "missing ROWS"),
+            ("FETCH FIRST ROWS", "missing ONLY"),
+            ("FETCH NEXT 1 ROW WITH", "missing TIES"),
+            ("FETCH FIRST 1.5 ROWS ONLY", "missing row count"),
+        ] {
+            source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+            assert!(matches!(
+                FetchClause::parse(&source, 0),
+                Err(err) if err.cause == cause
+            ));
+        }
+        // without a count
+        let text = "FETCH NEXT ROW ONLY";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            FetchClause::parse(&source, 0),
+            Ok((clause, index)) if clause.count.is_none() && !clause.with_ties && index == 4
+        ));
+        // with ties
+        let text = "FETCH FIRST ? ROWS WITH TIES";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            FetchClause::parse(&source, 0),
+            Ok((clause, index)) if eq_ignore_span(&clause.count, &Some(RowCount::PARAM(ParamExpr::new("?"))))
+                && clause.with_ties && clause.span.range() == (0..28) && index == 6
+        ));
+    }
+
+    #[test]
+    fn it_parse_stmt() {
+        // ties are only defined by an order
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t FETCH FIRST 1 ROWS WITH TIES"),
+            Err(err) if err.cause == "WITH TIES requires ORDER BY" && err.start == 16 && err.end == 44
+        ));
+        assert!(parse_stmt("SELECT a FROM t ORDER BY a FETCH FIRST 1 ROWS WITH TIES").is_ok());
+    }
+}
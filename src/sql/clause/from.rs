@@ -1,36 +1,76 @@
+use std::fmt;
+
 use rsdb::Named;
 
 use crate::sql::{
     err::SyntaxError,
     expr::{alias::AliasExpr, items::ItemsExpr},
-    frag::from_item::FromItem,
-    lexer::{lexer::Lexer, mat::LexerMatch},
-    parser::{LexerParser, SyntaxPattern},
+    format::{fmt_sql, FormatOptions, ToSql},
+    frag::{from_item::FromItem, join::JoinExpr},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, LexerStart, SyntaxPattern},
+    span::{Span, Spanned},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct FromClause {
-    pub items: ItemsExpr<AliasExpr<FromItem>>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FromClause<'a> {
+    pub items: ItemsExpr<AliasExpr<'a, FromItem<'a>>>,
+    pub span: Span,
 }
 
-impl Named for FromClause {
+impl Named for FromClause<'_> {
     const NAMED: &'static str = "from clause";
 }
 
-impl LexerParser for FromClause {
-    fn parse(source: &SyntaxPattern, index: usize) -> Result<(Self, usize), SyntaxError> {
+impl Spanned for FromClause<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for FromClause<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
         match source.items.get(index) {
-            Some(lexer) => match lexer {
-                Lexer::FROM(_) => match ItemsExpr::parse(source, index + 1) {
-                    Ok((items, end_index)) => Ok(((FromClause { items }), end_index)),
+            Some(Lexer::FROM(_)) => {
+                match ItemsExpr::parse_with(
+                    source,
+                    index + 1,
+                    FromItem::START,
+                    JoinExpr::parse_joined,
+                ) {
+                    Ok((items, end_index)) => match items.min_len_check(source, index + 1, 1) {
+                        Some(err) => Err(err),
+                        None => Ok((
+                            FromClause {
+                                items,
+                                span: source.span(index, end_index),
+                            },
+                            end_index,
+                        )),
+                    },
                     Err(err) => Err(err),
-                },
-                _ => Err(SyntaxError::new_missing(lexer.value(), Self::NAMED)),
-            },
-            None => Err(SyntaxError::new_missing(
-                LexerMatch::new_eof(&source.text),
-                Self::NAMED,
-            )),
+                }
+            }
+            _ => Err(source.missing(index, Self::NAMED, &["FROM"])),
         }
     }
 }
+
+impl ToSql for FromClause<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let items: Vec<String> = self
+            .items
+            .items
+            .iter()
+            .map(|item| item.to_sql_nested(options, false))
+            .collect();
+        options.clause("FROM", &items, ",")
+    }
+}
+
+impl fmt::Display for FromClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
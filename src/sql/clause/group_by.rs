@@ -0,0 +1,151 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    err::SyntaxError,
+    expr::{aggregate::AggregateExpr, expr::Expr, items::ItemsExpr},
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupByClause<'a> {
+    pub items: ItemsExpr<Expr<'a>>,
+    pub span: Span,
+}
+
+impl Named for GroupByClause<'_> {
+    const NAMED: &'static str = "group by clause";
+}
+
+impl Spanned for GroupByClause<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for GroupByClause<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        match (source.items.get(index), source.items.get(index + 1)) {
+            (Some(Lexer::GROUP(_)), Some(Lexer::BY(_))) => {
+                let (items, end_index) = ItemsExpr::<Expr>::parse(source, index + 2)?;
+                if let Some(err) = items.min_len_check(source, index + 2, 1) {
+                    return Err(err);
+                }
+                if let Some(span) = items.items.iter().find_map(AggregateExpr::find) {
+                    return Err(
+                        source.invalid(span, "aggregate functions are not allowed in GROUP BY")
+                    );
+                }
+                Ok((
+                    GroupByClause {
+                        items,
+                        span: source.span(index, end_index),
+                    },
+                    end_index,
+                ))
+            }
+            (Some(Lexer::GROUP(_)), _) => Err(source.missing(index + 1, "BY", &["BY"])),
+            _ => Err(source.missing(index, Self::NAMED, &["GROUP"])),
+        }
+    }
+}
+
+impl ToSql for GroupByClause<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let items: Vec<String> = self
+            .items
+            .items
+            .iter()
+            .map(|item| item.to_sql(options))
+            .collect();
+        options.clause("GROUP BY", &items, ",")
+    }
+}
+
+impl fmt::Display for GroupByClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::{
+        expr::{expr::Expr, ident::IdentExpr, name::NameExpr},
+        lexer::pattern::LexerPattern,
+        parser::{parse_stmt, LexerParser, SyntaxPattern},
+        span::eq_ignore_span,
+    };
+
+    use super::GroupByClause;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(GroupByClause::NAMED, "group by clause");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            GroupByClause::parse(&source, 0),
+            Err(err) if err.cause == "missing group by clause"
+        ));
+        // GROUP without BY
+        let text = "GROUP a";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            GroupByClause::parse(&source, 0),
+            Err(err) if err.cause == "missing BY"
+        ));
+        // no items
+        let text = "GROUP BY";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            GroupByClause::parse(&source, 0),
+            Err(err) if err.cause == "missing items"
+        ));
+        // aggregates are computed per group
+        let text = "GROUP BY a, count(b)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            GroupByClause::parse(&source, 0),
+            Err(err) if err.cause == "aggregate functions are not allowed in GROUP BY" && err.start == 12
+        ));
+        let text = "GROUP BY a, t.b";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            GroupByClause::parse(&source, 0),
+            Ok((clause, index)) if eq_ignore_span(&clause.items.items, &vec![
+                Expr::NAME(NameExpr::new("a")),
+                Expr::NAME(NameExpr::new_parts(vec![
+                    IdentExpr::new("t"),
+                    IdentExpr::new("b"),
+                ])),
+            ]) && clause.span.range() == (0..15) && index == 7
+        ));
+    }
+
+    #[test]
+    fn it_parse_stmt() {
+        assert!(parse_stmt("SELECT a, COUNT(*) FROM t GROUP BY a HAVING SUM(b) > 1").is_ok());
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t WHERE COUNT(*) > 1"),
+            Err(err) if err.cause == "aggregate functions are not allowed in WHERE"
+                && err.start == 22 && err.end == 30
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t HAVING a GROUP BY a"),
+            Err(err) if err.cause == "expected one of operator, EXCEPT, FETCH, INTERSECT, LIMIT, OFFSET, ORDER, UNION, ';', got GROUP"
+                && err.start == 25
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    err::SyntaxError,
+    expr::expr::Expr,
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HavingClause<'a> {
+    pub expr: Expr<'a>,
+    pub span: Span,
+}
+
+impl Named for HavingClause<'_> {
+    const NAMED: &'static str = "having clause";
+}
+
+impl Spanned for HavingClause<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for HavingClause<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        match source.items.get(index) {
+            Some(Lexer::HAVING(_)) => match Expr::parse(source, index + 1) {
+                Ok((expr, end_index)) => Ok((
+                    HavingClause {
+                        expr,
+                        span: source.span(index, end_index),
+                    },
+                    end_index,
+                )),
+                Err(err) => Err(err),
+            },
+            _ => Err(source.missing(index, Self::NAMED, &["HAVING"])),
+        }
+    }
+}
+
+impl ToSql for HavingClause<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        options.condition("HAVING", &self.expr)
+    }
+}
+
+impl fmt::Display for HavingClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::{
+        expr::{
+            aggregate::AggregateExpr,
+            binary::{BinaryExpr, BinaryOp},
+            expr::Expr,
+            literal::LiteralExpr,
+        },
+        lexer::pattern::LexerPattern,
+        parser::{LexerParser, SyntaxPattern},
+        span::{eq_ignore_span, Span},
+    };
+
+    use super::HavingClause;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(HavingClause::NAMED, "having clause");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            HavingClause::parse(&source, 0),
+            Err(err) if err.cause == "missing having clause"
+        ));
+        let text = "HAVING";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            HavingClause::parse(&source, 0),
+            Err(err) if err.cause == "missing expression"
+        ));
+        let text = "HAVING COUNT(*) > 1";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            HavingClause::parse(&source, 0),
+            Ok((clause, index)) if eq_ignore_span(&clause.expr, &Expr::BINARY(BinaryExpr::new(
+                Expr::AGGREGATE(AggregateExpr::new_count_star()),
+                BinaryOp::GT,
+                Expr::LITERAL(LiteralExpr::INTEGER("1".into(), Span::default())),
+            ))) && clause.span.range() == (0..19) && index == 7
+        ));
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    err::SyntaxError,
+    format::{fmt_sql, FormatOptions, ToSql},
+    frag::row_count::RowCount,
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LimitClause<'a> {
+    pub count: RowCount<'a>,
+    pub span: Span,
+}
+
+impl Named for LimitClause<'_> {
+    const NAMED: &'static str = "limit clause";
+}
+
+impl Spanned for LimitClause<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for LimitClause<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        match source.items.get(index) {
+            Some(Lexer::LIMIT(_)) => match RowCount::parse(source, index + 1) {
+                Ok((count, end_index)) => Ok((
+                    LimitClause {
+                        count,
+                        span: source.span(index, end_index),
+                    },
+                    end_index,
+                )),
+                Err(err) => Err(err),
+            },
+            _ => Err(source.missing(index, Self::NAMED, &["LIMIT"])),
+        }
+    }
+}
+
+impl ToSql for LimitClause<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        format!(
+            "{} {}",
+            options.keyword("LIMIT"),
+            self.count.to_sql(options)
+        )
+    }
+}
+
+impl fmt::Display for LimitClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::{
+        expr::param::ParamExpr,
+        frag::row_count::RowCount,
+        lexer::pattern::LexerPattern,
+        parser::{parse_stmt, LexerParser, SyntaxPattern},
+        span::eq_ignore_span,
+    };
+
+    use super::LimitClause;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(LimitClause::NAMED, "limit clause");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            LimitClause::parse(&source, 0),
+            Err(err) if err.cause == "missing limit clause"
+        ));
+        // no row count
+        let text = "LIMIT";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            LimitClause::parse(&source, 0),
+            Err(err) if err.cause == "missing row count"
+        ));
+        let text = "LIMIT $1";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            LimitClause::parse(&source, 0),
+            Ok((clause, index)) if eq_ignore_span(&clause.count, &RowCount::PARAM(ParamExpr::new("$1")))
+                && clause.span.range() == (0..8) && index == 2
+        ));
+    }
+
+    #[test]
+    fn it_parse_stmt() {
+        // LIMIT is not combined with FETCH
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t LIMIT 1 FETCH FIRST 1 ROWS ONLY"),
+            Err(err) if err.cause == "expected one of OFFSET, ';', got FETCH"
+        ));
+        // the row count is an integer or a parameter
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t LIMIT 'a'"),
+            Err(err) if err.cause == "expected one of integer, parameter, got string"
+        ));
+    }
+}
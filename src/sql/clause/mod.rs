@@ -1,2 +1,10 @@
+pub mod fetch;
 pub mod from;
+pub mod group_by;
+pub mod having;
+pub mod limit;
+pub mod offset;
+pub mod order_by;
 pub mod select;
+pub mod r#where;
+pub mod with;
@@ -0,0 +1,121 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    err::SyntaxError,
+    format::{fmt_sql, FormatOptions, ToSql},
+    frag::row_count::RowCount,
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+/// `OFFSET m [ROW | ROWS]`, the noise words are not kept
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OffsetClause<'a> {
+    pub count: RowCount<'a>,
+    pub span: Span,
+}
+
+impl Named for OffsetClause<'_> {
+    const NAMED: &'static str = "offset clause";
+}
+
+impl Spanned for OffsetClause<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for OffsetClause<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        match source.items.get(index) {
+            Some(Lexer::OFFSET(_)) => {
+                let (count, count_end_index) = RowCount::parse(source, index + 1)?;
+                let end_index = match source.items.get(count_end_index) {
+                    Some(Lexer::ROW(_) | Lexer::ROWS(_)) => count_end_index + 1,
+                    _ => {
+                        source.expect(count_end_index, &["ROWS"]);
+                        count_end_index
+                    }
+                };
+                Ok((
+                    OffsetClause {
+                        count,
+                        span: source.span(index, end_index),
+                    },
+                    end_index,
+                ))
+            }
+            _ => Err(source.missing(index, Self::NAMED, &["OFFSET"])),
+        }
+    }
+}
+
+impl ToSql for OffsetClause<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        format!(
+            "{} {}",
+            options.keyword("OFFSET"),
+            self.count.to_sql(options)
+        )
+    }
+}
+
+impl fmt::Display for OffsetClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::{
+        expr::literal::LiteralExpr,
+        frag::row_count::RowCount,
+        lexer::pattern::LexerPattern,
+        parser::{LexerParser, SyntaxPattern},
+        span::{eq_ignore_span, Span},
+    };
+
+    use super::OffsetClause;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(OffsetClause::NAMED, "offset clause");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            OffsetClause::parse(&source, 0),
+            Err(err) if err.cause == "missing offset clause"
+        ));
+        let text = "OFFSET 'a'";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            OffsetClause::parse(&source, 0),
+            Err(err) if err.cause == "missing row count"
+        ));
+        // with and without ROWS
+        let text = "OFFSET 20";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            OffsetClause::parse(&source, 0),
+            Ok((clause, index)) if eq_ignore_span(&clause.count, &RowCount::LITERAL(LiteralExpr::INTEGER("20".into(), Span::default())))
+                && index == 2
+        ));
+        let text = "OFFSET 1 ROW";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            OffsetClause::parse(&source, 0),
+            Ok((clause, index)) if clause.span.range() == (0..12) && index == 3
+        ));
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    clause::select::SelectClause,
+    err::SyntaxError,
+    expr::{expr::Expr, items::ItemsExpr, name::NameExpr},
+    format::{fmt_sql, FormatOptions, ToSql},
+    frag::{
+        order_by_item::{OrderByItem, OrderByKey},
+        select_item::SelectItem,
+    },
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderByClause<'a> {
+    pub items: ItemsExpr<OrderByItem<'a>>,
+    pub span: Span,
+}
+
+impl Named for OrderByClause<'_> {
+    const NAMED: &'static str = "order by clause";
+}
+
+impl Spanned for OrderByClause<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for OrderByClause<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        match (source.items.get(index), source.items.get(index + 1)) {
+            (Some(Lexer::ORDER(_)), Some(Lexer::BY(_))) => {
+                match ItemsExpr::parse(source, index + 2) {
+                    Ok((items, end_index)) => match items.min_len_check(source, index + 2, 1) {
+                        Some(err) => Err(err),
+                        None => Ok((
+                            OrderByClause {
+                                items,
+                                span: source.span(index, end_index),
+                            },
+                            end_index,
+                        )),
+                    },
+                    Err(err) => Err(err),
+                }
+            }
+            (Some(Lexer::ORDER(_)), _) => Err(source.missing(index + 1, "BY", &["BY"])),
+            _ => Err(source.missing(index, Self::NAMED, &["ORDER"])),
+        }
+    }
+}
+
+impl<'a> OrderByClause<'a> {
+    /// Resolves the items referring to the select list
+    ///
+    /// A single identifier naming a select alias becomes an alias reference, a position
+    /// must be within the select list. A `*` or a missing select clause hides the length
+    /// of the select list, only positions below 1 are rejected then.
+    pub fn resolve(
+        mut self,
+        source: &SyntaxPattern<'a>,
+        select_clause: Option<&SelectClause<'a>>,
+    ) -> Result<Self, SyntaxError<'a>> {
+        let select_items = select_clause.map_or(&[][..], |clause| &clause.items.items);
+        let has_star = select_clause.is_none()
+            || select_items.iter().any(|item| {
+                matches!(
+                    item.value,
+                    SelectItem::STAR(_) | SelectItem::QUALIFIEDSTAR(..)
+                )
+            });
+        for item in self.items.items.iter_mut() {
+            match &item.key {
+                OrderByKey::ORDINAL(position, span)
+                    if *position == 0 || (!has_star && *position > select_items.len()) =>
+                {
+                    return Err(source.invalid(
+                        *span,
+                        &format!("ORDER BY position {} is not in select list", position),
+                    ));
+                }
+                OrderByKey::EXPR(Expr::NAME(NameExpr { parts, .. })) if parts.len() == 1 => {
+                    let ident = &parts[0];
+                    let is_alias = select_items.iter().any(|select_item| {
+                        matches!(&select_item.alias, Some(alias) if alias.value == ident.value)
+                    });
+                    if is_alias {
+                        item.key = OrderByKey::ALIAS(ident.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(self)
+    }
+}
+
+impl ToSql for OrderByClause<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let items: Vec<String> = self
+            .items
+            .items
+            .iter()
+            .map(|item| item.to_sql(options))
+            .collect();
+        options.clause("ORDER BY", &items, ",")
+    }
+}
+
+impl fmt::Display for OrderByClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::{
+        clause::select::SelectClause,
+        expr::{expr::Expr, ident::IdentExpr, name::NameExpr},
+        frag::order_by_item::{NullsOrder, OrderByKey, OrderDirection},
+        lexer::pattern::LexerPattern,
+        parser::{parse_stmt, LexerParser, SyntaxPattern},
+        span::{eq_ignore_span, Span},
+    };
+
+    use super::OrderByClause;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(OrderByClause::NAMED, "order by clause");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            OrderByClause::parse(&source, 0),
+            Err(err) if err.cause == "missing order by clause"
+        ));
+        // ORDER without BY
+        let text = "ORDER a";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            OrderByClause::parse(&source, 0),
+            Err(err) if err.cause == "missing BY"
+        ));
+        // no items
+        let text = "ORDER BY";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            OrderByClause::parse(&source, 0),
+            Err(err) if err.cause == "missing items"
+        ));
+        // several items
+        let text = "ORDER BY a DESC, 1 NULLS LAST";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            OrderByClause::parse(&source, 0),
+            Ok((clause, index)) if clause.items.items.len() == 2
+                && clause.items.items[0].direction == Some(OrderDirection::DESC)
+                && eq_ignore_span(&clause.items.items[1].key, &OrderByKey::ORDINAL(1, Span::default()))
+                && clause.items.items[1].nulls == Some(NullsOrder::LAST)
+                && clause.span.range() == (0..29)
+                && index == 8
+        ));
+    }
+
+    #[test]
+    fn it_resolve() {
+        let text = "SELECT a AS x, b ORDER BY x, a, 2";
+        let source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        let (select_clause, index) = SelectClause::parse(&source, 0).unwrap();
+        let (clause, _) = OrderByClause::parse(&source, index).unwrap();
+        let clause = clause.resolve(&source, Some(&select_clause)).unwrap();
+        assert!(eq_ignore_span(
+            &clause.items.items[0].key,
+            &OrderByKey::ALIAS(IdentExpr::new("x"))
+        ));
+        assert!(eq_ignore_span(
+            &clause.items.items[1].key,
+            &OrderByKey::EXPR(Expr::NAME(NameExpr::new("a")))
+        ));
+        assert!(eq_ignore_span(
+            &clause.items.items[2].key,
+            &OrderByKey::ORDINAL(2, Span::default())
+        ));
+        // position out of the select list
+        let text = "SELECT a, b ORDER BY 3";
+        let source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        let (select_clause, index) = SelectClause::parse(&source, 0).unwrap();
+        let (clause, _) = OrderByClause::parse(&source, index).unwrap();
+        assert!(matches!(
+            clause.resolve(&source, Some(&select_clause)),
+            Err(err) if err.cause == "ORDER BY position 3 is not in select list" && err.start == 21
+        ));
+        // a star hides the length of the select list
+        let text = "SELECT *, a ORDER BY 3";
+        let source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        let (select_clause, index) = SelectClause::parse(&source, 0).unwrap();
+        let (clause, _) = OrderByClause::parse(&source, index).unwrap();
+        assert!(clause.resolve(&source, Some(&select_clause)).is_ok());
+    }
+
+    #[test]
+    fn it_parse_stmt() {
+        assert!(parse_stmt("SELECT a FROM t ORDER BY a DESC NULLS LAST").is_ok());
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t ORDER BY a NULLS"),
+            Err(err) if err.cause == "expected one of FIRST, LAST, got end of input"
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT a, b FROM t ORDER BY 0"),
+            Err(err) if err.cause == "ORDER BY position 0 is not in select list"
+                && err.start == 28 && err.end == 29
+        ));
+    }
+}
@@ -1,44 +1,240 @@
-use rsdb::Named;
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
 
 use crate::sql::{
     err::SyntaxError,
-    expr::{alias::AliasExpr, items::ItemsExpr},
+    expr::{alias::AliasExpr, expr::Expr, items::ItemsExpr},
+    format::{fmt_sql, FormatOptions, ToSql},
     frag::select_item::SelectItem,
-    lexer::{lexer::Lexer, mat::LexerMatch},
+    lexer::lexer::Lexer,
     parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
 };
 
+/// Set quantifier of the select list
+///
+/// `DISTINCT ON (exprs)` keeps the first row of each group of equal `exprs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum SelectQuantifier<'a> {
+    ALL,
+    DISTINCT,
+    DISTINCTON(ItemsExpr<Expr<'a>>),
+}
+
+impl NamedEnum for SelectQuantifier<'_> {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::ALL => "ALL",
+            Self::DISTINCT => "DISTINCT",
+            Self::DISTINCTON(_) => "DISTINCT ON",
+        }
+    }
+}
+
+impl ToSql for SelectQuantifier<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        match self {
+            Self::DISTINCTON(exprs) => {
+                let exprs: Vec<String> = exprs
+                    .items
+                    .iter()
+                    .map(|expr| expr.to_sql(options))
+                    .collect();
+                format!("{} ({})", options.keyword(self.name()), exprs.join(", "))
+            }
+            _ => options.keyword(self.name()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SelectClause {
-    pub items: ItemsExpr<AliasExpr<SelectItem>>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectClause<'a> {
+    pub quantifier: Option<SelectQuantifier<'a>>,
+    pub items: ItemsExpr<AliasExpr<'a, SelectItem<'a>>>,
+    pub span: Span,
 }
 
-impl Named for SelectClause {
+impl Named for SelectClause<'_> {
     const NAMED: &'static str = "select clause";
 }
 
-impl LexerParser for SelectClause {
-    fn parse(source: &SyntaxPattern, index: usize) -> Result<(Self, usize), SyntaxError> {
-        match source.items.get(index) {
-            Some(lexer) => match lexer {
-                Lexer::SELECT(_) => match ItemsExpr::parse(source, index + 1) {
-                    Ok((items, end_index)) => match items.min_len_check(source, index + 1, 1) {
-                        Some(err) => Err(err),
-                        None => Ok((
-                            SelectClause {
-                                items: items.clone(),
-                            },
-                            end_index,
-                        )),
-                    },
-                    Err(err) => Err(err),
-                },
-                _ => Err(SyntaxError::new_missing(lexer.value(), Self::NAMED)),
+impl Spanned for SelectClause<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for SelectClause<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        if !matches!(source.items.get(index), Some(Lexer::SELECT(_))) {
+            return Err(source.missing(index, Self::NAMED, &["SELECT"]));
+        }
+        let (quantifier, items_index) = match (
+            source.items.get(index + 1),
+            source.items.get(index + 2),
+            source.items.get(index + 3),
+        ) {
+            (Some(Lexer::ALL(_)), _, _) => (Some(SelectQuantifier::ALL), index + 2),
+            (Some(Lexer::DISTINCT(_)), Some(Lexer::ON(_)), Some(Lexer::LPAREN(_))) => {
+                let (exprs, end_index) = ItemsExpr::<Expr>::parse(source, index + 4)?;
+                if let Some(err) = exprs.min_len_check(source, index + 4, 1) {
+                    return Err(err);
+                }
+                match source.items.get(end_index) {
+                    Some(Lexer::RPAREN(_)) => {
+                        (Some(SelectQuantifier::DISTINCTON(exprs)), end_index + 1)
+                    }
+                    _ => return Err(source.missing(end_index, ")", &["')'"])),
+                }
+            }
+            (Some(Lexer::DISTINCT(_)), Some(Lexer::ON(_)), _) => {
+                return Err(source.missing(index + 3, "(", &["'('"]))
+            }
+            (Some(Lexer::DISTINCT(_)), _, _) => {
+                source.expect(index + 2, &["ON"]);
+                (Some(SelectQuantifier::DISTINCT), index + 2)
+            }
+            // the select items record the lexers they may start with there as well
+            _ => {
+                source.expect(index + 1, &["ALL", "DISTINCT"]);
+                (None, index + 1)
+            }
+        };
+        let (items, end_index) = ItemsExpr::parse(source, items_index)?;
+        if let Some(err) = items.min_len_check(source, items_index, 1) {
+            return Err(err);
+        }
+        Ok((
+            SelectClause {
+                quantifier,
+                items,
+                span: source.span(index, end_index),
             },
-            None => Err(SyntaxError::new_missing(
-                LexerMatch::new_eof(&source.text),
-                Self::NAMED,
-            )),
+            end_index,
+        ))
+    }
+}
+
+impl ToSql for SelectClause<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let items: Vec<String> = self
+            .items
+            .items
+            .iter()
+            .map(|item| item.to_sql(options))
+            .collect();
+        match &self.quantifier {
+            Some(quantifier) => options.clause_head(
+                &format!(
+                    "{} {}",
+                    options.keyword("SELECT"),
+                    quantifier.to_sql(options)
+                ),
+                &items,
+                ",",
+            ),
+            None => options.clause("SELECT", &items, ","),
         }
     }
 }
+
+impl fmt::Display for SelectClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::{
+        lexer::pattern::LexerPattern,
+        parser::{LexerParser, SyntaxPattern},
+    };
+
+    use super::{SelectClause, SelectQuantifier};
+
+    #[test]
+    fn it_name() {
+        assert_eq!(SelectClause::NAMED, "select clause");
+        assert_eq!(SelectQuantifier::DISTINCT.name(), "DISTINCT");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            SelectClause::parse(&source, 0),
+            Err(err) if err.cause == "missing select clause"
+        ));
+        // a select item or a quantifier is expected after SELECT
+        let text = "SELECT";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectClause::parse(&source, 0).map_err(|err| source.expected_error(err)),
+            Err(err) if err.cause == "expected one of identifier, literal, parameter, ALL, DISTINCT, NOT, '(', '*', '-', got end of input"
+        ));
+        let text = "SELECT DISTINCT";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectClause::parse(&source, 0).map_err(|err| source.expected_error(err)),
+            Err(err) if err.expected == ["identifier", "literal", "parameter", "NOT", "ON", "'('", "'*'", "'-'"]
+        ));
+        // no quantifier
+        let text = "SELECT a, b FROM t";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectClause::parse(&source, 0),
+            Ok((clause, index)) if clause.quantifier.is_none() && clause.items.items.len() == 2
+                && clause.span.range() == (0..11) && index == 4
+        ));
+        // all and distinct
+        let text = "SELECT ALL a";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectClause::parse(&source, 0),
+            Ok((clause, 3)) if clause.quantifier == Some(SelectQuantifier::ALL)
+        ));
+        let text = "SELECT DISTINCT a, b";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectClause::parse(&source, 0),
+            Ok((clause, 5)) if clause.quantifier == Some(SelectQuantifier::DISTINCT)
+                && clause.to_string() == "SELECT DISTINCT a, b"
+        ));
+        // distinct on
+        let text = "select distinct on (a, t.b) a, c";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectClause::parse(&source, 0),
+            Ok((clause, 13)) if matches!(
+                &clause.quantifier,
+                Some(SelectQuantifier::DISTINCTON(exprs)) if exprs.items.len() == 2
+            ) && clause.to_string() == "SELECT DISTINCT ON (a, t.b) a, c"
+        ));
+        let text = "SELECT DISTINCT ON a";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectClause::parse(&source, 0),
+            Err(err) if err.cause == "missing (" && err.start == 19
+        ));
+        let text = "SELECT DISTINCT ON () a";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectClause::parse(&source, 0),
+            Err(err) if err.cause == "missing expression" && err.start == 20
+        ));
+        let text = "SELECT DISTINCT ON (a b";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectClause::parse(&source, 0),
+            Err(err) if err.cause == "missing )" && err.start == 22
+        ));
+    }
+}
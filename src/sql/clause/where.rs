@@ -0,0 +1,145 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    err::SyntaxError,
+    expr::{aggregate::AggregateExpr, expr::Expr},
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WhereClause<'a> {
+    pub expr: Expr<'a>,
+    pub span: Span,
+}
+
+impl Named for WhereClause<'_> {
+    const NAMED: &'static str = "where clause";
+}
+
+impl Spanned for WhereClause<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for WhereClause<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        match source.items.get(index) {
+            Some(Lexer::WHERE(_)) => {
+                let (expr, end_index) = Expr::parse(source, index + 1)?;
+                // rows are filtered before they are aggregated
+                if let Some(span) = AggregateExpr::find(&expr) {
+                    return Err(
+                        source.invalid(span, "aggregate functions are not allowed in WHERE")
+                    );
+                }
+                Ok((
+                    WhereClause {
+                        expr,
+                        span: source.span(index, end_index),
+                    },
+                    end_index,
+                ))
+            }
+            _ => Err(source.missing(index, Self::NAMED, &["WHERE"])),
+        }
+    }
+}
+
+impl ToSql for WhereClause<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        options.condition("WHERE", &self.expr)
+    }
+}
+
+impl fmt::Display for WhereClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::{
+        expr::{
+            binary::{BinaryExpr, BinaryOp},
+            expr::Expr,
+            literal::LiteralExpr,
+            name::NameExpr,
+        },
+        format::{FormatOptions, KeywordCase, ToSql},
+        lexer::pattern::LexerPattern,
+        parser::{LexerParser, SyntaxPattern},
+        span::{eq_ignore_span, Span},
+    };
+
+    use super::WhereClause;
+
+    fn parse(text: &str) -> Result<(WhereClause<'_>, usize), String> {
+        let source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        WhereClause::parse(&source, 0).map_err(|err| err.cause)
+    }
+
+    fn eq<'a>(name: &'a str, value: &'a str) -> Expr<'a> {
+        Expr::BINARY(BinaryExpr::new(
+            Expr::NAME(NameExpr::new(name)),
+            BinaryOp::EQ,
+            Expr::LITERAL(LiteralExpr::INTEGER(value.into(), Span::default())),
+        ))
+    }
+
+    #[test]
+    fn it_name() {
+        assert_eq!(WhereClause::NAMED, "where clause");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        assert!(matches!(parse(""), Err(cause) if cause == "missing where clause"));
+        assert!(matches!(parse("WHERE"), Err(cause) if cause == "missing expression"));
+        // stops before the next clause
+        assert!(matches!(
+            parse("WHERE a = 1 AND b = 2 ORDER BY a"),
+            Ok((clause, index)) if eq_ignore_span(&clause.expr, &Expr::BINARY(BinaryExpr::new(
+                eq("a", "1"),
+                BinaryOp::AND,
+                eq("b", "2"),
+            ))) && clause.span.range() == (0..21) && index == 8
+        ));
+        // rows are filtered before they are aggregated
+        assert!(matches!(
+            parse("WHERE a = 1 OR COUNT(*) > 1"),
+            Err(cause) if cause == "aggregate functions are not allowed in WHERE"
+        ));
+        // a long OR chain is checked without recursion
+        let text = format!("WHERE {}", vec!["a = 1"; 10_000].join(" OR "));
+        assert!(matches!(parse(&text), Ok((_, index)) if index == 40_000));
+    }
+
+    #[test]
+    fn it_to_sql() {
+        let (clause, _) = parse("where a = 1 and (b = 2 or c = 3)").unwrap();
+        assert_eq!(clause.to_string(), "WHERE a = 1 AND (b = 2 OR c = 3)");
+        let options = FormatOptions {
+            keyword_case: KeywordCase::LOWER,
+            line_width: 20,
+            ..FormatOptions::default()
+        };
+        assert_eq!(
+            clause.to_sql(&options),
+            "where\n  a = 1\n  and (b = 2 or c = 3)"
+        );
+        // a lone OR is not wrapped
+        let (clause, _) = parse("WHERE a = 1 OR b = 2").unwrap();
+        assert_eq!(clause.to_string(), "WHERE a = 1 OR b = 2");
+    }
+}
@@ -0,0 +1,183 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    err::SyntaxError,
+    expr::items::ItemsExpr,
+    format::{fmt_sql, FormatOptions, ToSql},
+    frag::cte::Cte,
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+/// `WITH [RECURSIVE] cte, ...`, a recursive clause lets every query refer to all names
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithClause<'a> {
+    pub recursive: bool,
+    pub ctes: ItemsExpr<Cte<'a>>,
+    pub span: Span,
+}
+
+impl Named for WithClause<'_> {
+    const NAMED: &'static str = "with clause";
+}
+
+impl Spanned for WithClause<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for WithClause<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        match source.items.get(index) {
+            Some(Lexer::WITH(_)) => {
+                let (recursive, ctes_index) =
+                    match (source.items.get(index + 1), source.items.get(index + 2)) {
+                        // RECURSIVE is non reserved, followed by AS or `(` it is a query name
+                        (Some(Lexer::RECURSIVE(_)), next)
+                            if !matches!(next, Some(Lexer::ALIAS(_) | Lexer::LPAREN(_))) =>
+                        {
+                            (true, index + 2)
+                        }
+                        _ => {
+                            source.expect(index + 1, &["identifier", "RECURSIVE"]);
+                            (false, index + 1)
+                        }
+                    };
+                let (ctes, end_index) = ItemsExpr::<Cte>::parse(source, ctes_index)?;
+                if let Some(err) = ctes.min_len_check(source, ctes_index, 1) {
+                    return Err(err);
+                }
+                // a query name is only defined once
+                for (i, cte) in ctes.items.iter().enumerate() {
+                    if ctes.items[..i]
+                        .iter()
+                        .any(|other| other.name.value == cte.name.value)
+                    {
+                        return Err(source.invalid(
+                            cte.name.span,
+                            &format!(
+                                "WITH query name \"{}\" specified more than once",
+                                cte.name.value
+                            ),
+                        ));
+                    }
+                }
+                Ok((
+                    WithClause {
+                        recursive,
+                        ctes,
+                        span: source.span(index, end_index),
+                    },
+                    end_index,
+                ))
+            }
+            _ => Err(source.missing(index, Self::NAMED, &["WITH"])),
+        }
+    }
+}
+
+impl ToSql for WithClause<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let items: Vec<String> = self
+            .ctes
+            .items
+            .iter()
+            .map(|cte| cte.to_sql(options))
+            .collect();
+        let keyword = match self.recursive {
+            true => "WITH RECURSIVE",
+            false => "WITH",
+        };
+        options.clause(keyword, &items, ",")
+    }
+}
+
+impl fmt::Display for WithClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::{
+        lexer::pattern::LexerPattern,
+        parser::{parse_stmt, LexerParser, SyntaxPattern},
+    };
+
+    use super::WithClause;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(WithClause::NAMED, "with clause");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            WithClause::parse(&source, 0),
+            Err(err) if err.cause == "missing with clause"
+        ));
+        // no ctes
+        let text = "WITH";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            WithClause::parse(&source, 0).map_err(|err| source.expected_error(err)),
+            Err(err) if err.cause == "expected one of identifier, RECURSIVE, got end of input"
+        ));
+        let text = "WITH RECURSIVE";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            WithClause::parse(&source, 0).map_err(|err| source.expected_error(err)),
+            Err(err) if err.cause == "expected identifier, got end of input"
+        ));
+        // several ctes
+        let text = "WITH RECURSIVE a AS (SELECT x FROM t), b AS (SELECT y FROM a) SELECT";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            WithClause::parse(&source, 0),
+            Ok((clause, index)) if clause.recursive && clause.ctes.items.len() == 2
+                && clause.span.range() == (0..61) && index == 19
+        ));
+        // non reserved keywords as query names
+        let text = "WITH recursive AS (SELECT x FROM t), materialized (y) AS MATERIALIZED (SELECT y FROM t)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            WithClause::parse(&source, 0),
+            Ok((clause, _)) if !clause.recursive && clause.ctes.items[0].name.value == "recursive"
+                && clause.ctes.items[1].name.value == "materialized"
+        ));
+        let text = "WITH RECURSIVE recursive (n) AS (SELECT 1 FROM t)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            WithClause::parse(&source, 0),
+            Ok((clause, _)) if clause.recursive && clause.ctes.items[0].name.value == "recursive"
+        ));
+        // duplicate names
+        let text = "WITH a AS (SELECT x FROM t), A AS (SELECT y FROM t)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            WithClause::parse(&source, 0),
+            Err(err) if err.cause == "WITH query name \"a\" specified more than once"
+                && err.start == 29 && err.end == 30
+        ));
+    }
+
+    #[test]
+    fn it_parse_stmt() {
+        assert!(parse_stmt("WITH RECURSIVE r AS (SELECT a FROM t) SELECT a FROM r").is_ok());
+        assert!(matches!(
+            parse_stmt("WITH r AS (SELECT a FROM t) FROM r"),
+            Err(err) if err.cause == "expected one of SELECT, '(', ',', got FROM"
+        ));
+    }
+}
@@ -1,68 +1,224 @@
-use regex::Regex;
+use std::borrow::Cow;
 
 use super::lexer::mat::LexerMatch;
 
+/// Syntax error, the source text is borrowed until `into_owned` is called
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SyntaxError {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyntaxError<'a> {
     pub cause: String,
-    pub text: String,
+    pub text: Cow<'a, str>,
     pub start: usize,
     pub end: usize,
+    pub help: Option<String>,
+    // the names are static, they are written but not read back
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub expected: Vec<&'static str>,
 }
 
-impl SyntaxError {
-    pub fn new_lexer(mat: LexerMatch, cause: &str) -> SyntaxError {
+impl<'a> SyntaxError<'a> {
+    pub fn new_lexer(mat: LexerMatch<'a>, cause: &str) -> SyntaxError<'a> {
         SyntaxError {
             cause: cause.to_owned(),
-            text: mat.text().to_owned(),
+            text: Cow::Borrowed(mat.text()),
             start: mat.start(),
             end: mat.end(),
+            help: None,
+            expected: vec![],
         }
     }
 
-    pub fn new_excpeted(mat: LexerMatch) -> SyntaxError {
+    pub fn new_excpeted(mat: LexerMatch<'a>) -> SyntaxError<'a> {
         SyntaxError {
-            cause: format!("excpeted {}", mat.as_str()),
-            text: mat.text().to_owned(),
+            cause: format!("expected {}", mat.as_str()),
+            text: Cow::Borrowed(mat.text()),
             start: mat.start(),
             end: mat.end(),
+            help: None,
+            expected: vec![],
         }
     }
 
-    pub fn new_missing(mat: LexerMatch, name: &str) -> SyntaxError {
+    pub fn new_unexpected(mat: LexerMatch<'a>) -> SyntaxError<'a> {
+        SyntaxError {
+            cause: format!("unexpected {}", mat.as_str()),
+            text: Cow::Borrowed(mat.text()),
+            start: mat.start(),
+            end: mat.end(),
+            help: None,
+            expected: vec![],
+        }
+    }
+
+    pub fn new_missing(mat: LexerMatch<'a>, name: &str) -> SyntaxError<'a> {
         SyntaxError {
             cause: format!("missing {}", name),
-            text: mat.text().to_owned(),
+            text: Cow::Borrowed(mat.text()),
+            start: mat.start(),
+            end: mat.end(),
+            help: None,
+            expected: vec![],
+        }
+    }
+
+    /// Lists the lexers which would have been accepted instead of `got`
+    pub fn new_expected_one_of(
+        mat: LexerMatch<'a>,
+        expected: Vec<&'static str>,
+        got: &str,
+    ) -> SyntaxError<'a> {
+        let cause = match expected.as_slice() {
+            [name] => format!("expected {}, got {}", name, got),
+            _ => format!("expected one of {}, got {}", expected.join(", "), got),
+        };
+        SyntaxError {
+            cause,
+            text: Cow::Borrowed(mat.text()),
             start: mat.start(),
             end: mat.end(),
+            help: None,
+            expected,
         }
     }
 
-    fn point(&self) -> (usize, usize) {
-        let regex = Regex::new(r"^(\r\n)|[\r\n]").unwrap();
-        let mut row = 0_usize;
-        let mut col = 0_usize;
-        let mut i = 0_usize;
-
-        while i < self.text.len() {
-            match regex.find_at(&self.text, i) {
-                Some(mat) => {
-                    row += 1;
-                    col = 0;
-                    i += mat.range().len();
-                }
-                None => {
-                    col += 1;
-                    i += 1;
-                }
+    /// Copies the source text, so the error can outlive it
+    pub fn into_owned(self) -> SyntaxError<'static> {
+        SyntaxError {
+            cause: self.cause,
+            text: Cow::Owned(self.text.into_owned()),
+            start: self.start,
+            end: self.end,
+            help: self.help,
+            expected: self.expected,
+        }
+    }
+
+    /// Attaches a help text, it is printed below the caret underline by `render`
+    pub fn with_help(mut self, help: &str) -> SyntaxError<'a> {
+        self.help = Some(help.to_owned());
+        self
+    }
+
+    /// 1-based line and column (in characters) of `start`
+    pub fn start_point(&self) -> (usize, usize) {
+        let (line, col, _) = self.locate(self.start);
+        (line, col)
+    }
+
+    /// 1-based line and column (in characters) of `end`
+    pub fn end_point(&self) -> (usize, usize) {
+        let (line, col, _) = self.locate(self.end);
+        (line, col)
+    }
+
+    /// Returns the line, the column and the byte offset of the line start,
+    /// `\r\n`, `\r` and `\n` are line breaks
+    fn locate(&self, offset: usize) -> (usize, usize, usize) {
+        let offset = offset.min(self.text.len());
+        let mut line = 1_usize;
+        let mut line_start = 0_usize;
+
+        for (i, c) in self.text[..offset].char_indices() {
+            if c == '\n' || (c == '\r' && !self.text[i + 1..].starts_with('\n')) {
+                line += 1;
+                line_start = i + 1;
             }
         }
 
-        (row, col)
+        let col = self.text[line_start..offset].chars().count() + 1;
+        (line, col, line_start)
     }
 
     pub fn msg(&self) -> String {
-        let (row, col) = self.point();
-        format!("SyntaxError: {} [{}, {}]", self.cause, row, col)
+        let (line, col) = self.start_point();
+        format!("SyntaxError: {} [{}, {}]", self.cause, line, col)
+    }
+
+    /// Renders the cause, the offending line with a caret underline and the help text
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```text
+    /// SyntaxError: missing select clause
+    ///  --> 2:1
+    ///   |
+    /// 2 | FROM t1
+    ///   | ^^^^
+    ///   = help: start the query with SELECT
+    /// ```
+    pub fn render(&self) -> String {
+        let (line, col, line_start) = self.locate(self.start);
+        let line_end = self.text[line_start..]
+            .find(['\r', '\n'])
+            .map_or(self.text.len(), |i| line_start + i);
+        let line_text = &self.text[line_start..line_end];
+        let start = self.start.clamp(line_start, line_end);
+        let end = self.end.clamp(start, line_end);
+
+        // tabs are kept, so the carets stay aligned with the line
+        let padding: String = self.text[line_start..start]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(self.text[start..end].chars().count().max(1));
+        let gutter = " ".repeat(line.to_string().len());
+
+        let mut out = format!("SyntaxError: {}\n", self.cause);
+        out += &format!("{} --> {}:{}\n", &gutter[1..], line, col);
+        out += &format!("{} |\n", gutter);
+        out += &format!("{} | {}\n", line, line_text);
+        out += &format!("{} | {}{}", gutter, padding, carets);
+        if let Some(help) = &self.help {
+            out += &format!("\n{} = help: {}", gutter, help);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::lexer::mat::LexerMatch;
+
+    use super::SyntaxError;
+
+    #[test]
+    fn it_point() {
+        let text = "SELECT a\r\nFROM t1\rWHERE\n  ¤b";
+        let err = SyntaxError::new_excpeted(LexerMatch::new(text, 26, 28));
+        assert_eq!(err.start_point(), (4, 3));
+        assert_eq!(err.end_point(), (4, 4));
+        assert_eq!(err.msg(), "SyntaxError: expected ¤ [4, 3]");
+        let err = SyntaxError::new_unexpected(LexerMatch::new(text, 10, 14));
+        assert_eq!(err.start_point(), (2, 1));
+        assert_eq!(err.end_point(), (2, 5));
+        // eof
+        let err = SyntaxError::new_missing(LexerMatch::new_eof(text), "a8f1");
+        assert_eq!(err.start_point(), (4, 4));
+    }
+
+    #[test]
+    fn it_render() {
+        let text = "SELECT a\nFROM t1 WHERE";
+        let err = SyntaxError::new_missing(LexerMatch::new(text, 9, 13), "3c5e");
+        assert_eq!(
+            err.render(),
+            "SyntaxError: missing 3c5e\n --> 2:1\n  |\n2 | FROM t1 WHERE\n  | ^^^^"
+        );
+        // eof with help
+        let err = SyntaxError::new_missing(LexerMatch::new_eof(text), "expression")
+            .with_help("add a condition");
+        assert_eq!(
+            err.render(),
+            "SyntaxError: missing expression\n --> 2:13\n  |\n2 | FROM t1 WHERE\n  |             ^\n  = help: add a condition"
+        );
+        // tabs & multi-line span
+        let text = "\tSELECT /* a\n*/";
+        let err = SyntaxError::new_lexer(LexerMatch::new(text, 8, text.len()), "e2d7");
+        assert_eq!(
+            err.render(),
+            "SyntaxError: e2d7\n --> 1:9\n  |\n1 | \tSELECT /* a\n  | \t       ^^^^"
+        );
     }
 }
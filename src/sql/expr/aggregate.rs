@@ -0,0 +1,333 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    err::SyntaxError,
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+use super::expr::Expr;
+
+/// Aggregate function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AggregateFunc {
+    COUNT,
+    SUM,
+    AVG,
+    MIN,
+    MAX,
+}
+
+impl NamedEnum for AggregateFunc {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::COUNT => "COUNT",
+            Self::SUM => "SUM",
+            Self::AVG => "AVG",
+            Self::MIN => "MIN",
+            Self::MAX => "MAX",
+        }
+    }
+}
+
+impl AggregateFunc {
+    /// The aggregate function of a name, the case does not matter
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// assert_eq!(AggregateFunc::from_name("count"), Some(AggregateFunc::COUNT));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_uppercase().as_str() {
+            "COUNT" => Some(Self::COUNT),
+            "SUM" => Some(Self::SUM),
+            "AVG" => Some(Self::AVG),
+            "MIN" => Some(Self::MIN),
+            "MAX" => Some(Self::MAX),
+            _ => None,
+        }
+    }
+}
+
+/// Aggregate Expression
+///
+/// `func([DISTINCT] expr)` or `COUNT(*)`, the argument is `None` for `*`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AggregateExpr<'a> {
+    pub func: AggregateFunc,
+    pub distinct: bool,
+    pub arg: Option<Box<Expr<'a>>>,
+    pub span: Span,
+}
+
+impl Named for AggregateExpr<'_> {
+    const NAMED: &'static str = "aggregate expression";
+}
+
+impl Spanned for AggregateExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for AggregateExpr<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>>
+    where
+        Self: Sized,
+    {
+        let func = match (source.items.get(index), source.items.get(index + 1)) {
+            (Some(Lexer::NAME(name)), Some(Lexer::LPAREN(_))) => {
+                AggregateFunc::from_name(name.as_str())
+            }
+            _ => None,
+        };
+        let func = match func {
+            Some(func) => func,
+            None => return Err(source.missing(index, Self::NAMED, &["identifier"])),
+        };
+
+        let (distinct, arg_index) = match source.items.get(index + 2) {
+            Some(Lexer::DISTINCT(_)) => (true, index + 3),
+            _ => {
+                source.expect(index + 2, &["DISTINCT"]);
+                (false, index + 2)
+            }
+        };
+        let (arg, arg_end_index) = match source.items.get(arg_index) {
+            Some(Lexer::STAR(_)) if func == AggregateFunc::COUNT && !distinct => {
+                (None, arg_index + 1)
+            }
+            Some(Lexer::STAR(_)) => {
+                return Err(source.invalid(
+                    source.span(arg_index, arg_index + 1),
+                    &format!("{}(*) is not allowed", func.name()),
+                ))
+            }
+            _ => {
+                let (arg, end_index) = Expr::parse(source, arg_index)?;
+                if let Some(span) = Self::find(&arg) {
+                    return Err(source.invalid(span, "aggregate function calls cannot be nested"));
+                }
+                (Some(Box::new(arg)), end_index)
+            }
+        };
+
+        match source.items.get(arg_end_index) {
+            Some(Lexer::RPAREN(_)) => Ok((
+                AggregateExpr {
+                    func,
+                    distinct,
+                    arg,
+                    span: source.span(index, arg_end_index + 1),
+                },
+                arg_end_index + 1,
+            )),
+            _ => Err(source.missing(arg_end_index, ")", &["')'"])),
+        }
+    }
+}
+
+impl<'a> AggregateExpr<'a> {
+    /// Creates a new aggregate of an expression
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = AggregateExpr::new(AggregateFunc::SUM, Expr::NAME(NameExpr::new("a")));
+    /// ```
+    pub fn new(func: AggregateFunc, arg: Expr<'a>) -> Self {
+        AggregateExpr {
+            func,
+            distinct: false,
+            arg: Some(Box::new(arg)),
+            span: Span::default(),
+        }
+    }
+
+    /// Creates a new `COUNT(*)`
+    pub fn new_count_star() -> Self {
+        AggregateExpr {
+            func: AggregateFunc::COUNT,
+            distinct: false,
+            arg: None,
+            span: Span::default(),
+        }
+    }
+
+    /// Whether the lexers at `index` start an aggregate call, e.g. `sum(`
+    pub fn is_start(source: &SyntaxPattern<'_>, index: usize) -> bool {
+        matches!(
+            (source.items.get(index), source.items.get(index + 1)),
+            (Some(Lexer::NAME(name)), Some(Lexer::LPAREN(_)))
+                if AggregateFunc::from_name(name.as_str()).is_some()
+        )
+    }
+
+    /// The span of the first aggregate call within the expression, if any
+    ///
+    /// Aggregates of subqueries belong to the subquery and are skipped. The expression is
+    /// walked with a stack instead of the recursive `Visitor`, a long `OR` chain is as
+    /// deep as it is long.
+    pub fn find(expr: &Expr<'a>) -> Option<Span> {
+        let mut stack = vec![expr];
+        while let Some(expr) = stack.pop() {
+            // children are pushed in reverse, the leftmost one is found first
+            match expr {
+                Expr::AGGREGATE(expr) => return Some(expr.span),
+                Expr::UNARY(expr) => stack.push(&expr.value),
+                Expr::BINARY(expr) => stack.extend([&*expr.right, &*expr.left]),
+                Expr::ISNULL(expr) => stack.push(&expr.value),
+                Expr::ISDISTINCT(expr) => stack.extend([&*expr.right, &*expr.left]),
+                Expr::BETWEEN(expr) => stack.extend([&*expr.high, &*expr.low, &*expr.value]),
+                Expr::INLIST(expr) => {
+                    stack.extend(expr.list.items.iter().rev());
+                    stack.push(&expr.value);
+                }
+                Expr::LIKE(expr) => stack.extend([&*expr.pattern, &*expr.value]),
+                Expr::INSUBQUERY(expr) => stack.push(&expr.value),
+                Expr::QUANTIFIED(expr) => stack.push(&expr.left),
+                Expr::NAME(_)
+                | Expr::LITERAL(_)
+                | Expr::PARAM(_)
+                | Expr::SUBQUERY(_)
+                | Expr::EXISTS(_) => {}
+            }
+        }
+        None
+    }
+}
+
+impl ToSql for AggregateExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let arg = match &self.arg {
+            Some(arg) => arg.to_sql(options),
+            None => "*".to_owned(),
+        };
+        match self.distinct {
+            true => format!(
+                "{}({} {})",
+                options.keyword(self.func.name()),
+                options.keyword("DISTINCT"),
+                arg
+            ),
+            false => format!("{}({})", options.keyword(self.func.name()), arg),
+        }
+    }
+}
+
+impl fmt::Display for AggregateExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::{
+        expr::{expr::Expr, name::NameExpr},
+        lexer::pattern::LexerPattern,
+        parser::{LexerParser, SyntaxPattern},
+        span::eq_ignore_span,
+    };
+
+    use super::{AggregateExpr, AggregateFunc};
+
+    #[test]
+    fn it_name() {
+        assert_eq!(AggregateExpr::NAMED, "aggregate expression");
+        assert_eq!(AggregateFunc::AVG.name(), "AVG");
+        assert_eq!(AggregateFunc::from_name("Max"), Some(AggregateFunc::MAX));
+        assert_eq!(AggregateFunc::from_name("upper"), None);
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            AggregateExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing aggregate expression"
+        ));
+        // not an aggregate function
+        let text = "upper(a)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(!AggregateExpr::is_start(&source, 0));
+        assert!(matches!(
+            AggregateExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing aggregate expression"
+        ));
+        // count(*)
+        let text = "count(*)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(AggregateExpr::is_start(&source, 0));
+        assert!(matches!(
+            AggregateExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &AggregateExpr::new_count_star()) && expr.span.range() == (0..8) && index == 4
+        ));
+        // distinct
+        let text = "COUNT(DISTINCT a)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            AggregateExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &AggregateExpr {
+                distinct: true,
+                ..AggregateExpr::new(AggregateFunc::COUNT, Expr::NAME(NameExpr::new("a")))
+            }) && index == 5
+        ));
+        // * is only counted
+        let text = "SUM(*)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            AggregateExpr::parse(&source, 0),
+            Err(err) if err.cause == "SUM(*) is not allowed" && err.start == 4
+        ));
+        // nested calls
+        let text = "max(min(a))";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            AggregateExpr::parse(&source, 0),
+            Err(err) if err.cause == "aggregate function calls cannot be nested" && err.start == 4 && err.end == 10
+        ));
+        // missing )
+        let text = "avg(a";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            AggregateExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing )"
+        ));
+    }
+
+    #[test]
+    fn it_find() {
+        let find = |text: &str| {
+            let source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+            AggregateExpr::find(&Expr::parse(&source, 0).unwrap().0).map(|span| span.range())
+        };
+        assert_eq!(find("a + 1"), None);
+        // the leftmost one
+        assert_eq!(find("a BETWEEN -sum(b) AND max(c)"), Some(11..17));
+        assert_eq!(find("a IN (1, avg(b)) OR min(c) > 1"), Some(9..15));
+        // subqueries have their own aggregates
+        assert_eq!(
+            find("a IN (SELECT max(b) FROM t) AND EXISTS (SELECT count(*) FROM u)"),
+            None
+        );
+        assert_eq!(find("count(*) > ALL (SELECT max(b) FROM t)"), Some(0..8));
+        // a long OR chain
+        let text = format!("{} OR count(*) > 0", vec!["a = 1"; 10_000].join(" OR "));
+        assert_eq!(find(&text), Some(90_000..90_008));
+    }
+}
@@ -1,58 +1,92 @@
+use std::fmt;
+
 use rsdb::Named;
 
 use crate::sql::{
     err::SyntaxError,
-    expr::name::NameExpr,
+    expr::ident::IdentExpr,
+    format::{fmt_sql, FormatOptions, ToSql},
     lexer::lexer::Lexer,
-    parser::{LexerParser, SyntaxPattern},
+    parser::{LexerParser, LexerStart, SyntaxPattern},
+    span::{Span, Spanned},
 };
 
 /// Alias Expression
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct AliasExpr<T>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AliasExpr<'a, T>
 where
-    T: Sized + LexerParser,
+    T: Sized,
 {
     pub value: T,
-    pub alias: Option<NameExpr>,
+    pub alias: Option<IdentExpr<'a>>,
+    pub span: Span,
 }
 
-impl<T> Named for AliasExpr<T>
+impl<T> Named for AliasExpr<'_, T>
 where
-    T: Sized + LexerParser,
+    T: Sized,
 {
     const NAMED: &'static str = "alias expression";
 }
 
-impl<T> LexerParser for AliasExpr<T>
+impl<T> LexerStart for AliasExpr<'_, T>
 where
-    T: Sized + LexerParser,
+    T: Sized + LexerStart,
 {
-    fn parse(source: &SyntaxPattern, index: usize) -> Result<(Self, usize), SyntaxError>
+    const START: &'static [&'static str] = T::START;
+}
+
+impl<'a, T> LexerParser<'a> for AliasExpr<'a, T>
+where
+    T: Sized + LexerParser<'a>,
+{
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>>
     where
         Self: Sized,
     {
         match T::parse(source, index) {
             Ok((value, value_end_index)) => match source.items.get(value_end_index) {
-                Some(lexer) => match lexer {
-                    Lexer::ALIAS(_) => match NameExpr::parse(source, value_end_index + 1) {
-                        Ok((alias, alias_end_index)) => {
-                            Ok((AliasExpr::new(value, alias), alias_end_index))
-                        }
-                        Err(err) => Err(err),
-                    },
-                    _ => Ok((AliasExpr::new_without_alias(value), value_end_index)),
+                // has AS & parse alias
+                Some(Lexer::ALIAS(_)) => match IdentExpr::parse(source, value_end_index + 1) {
+                    Ok((alias, alias_end_index)) => Ok((
+                        AliasExpr {
+                            span: source.span(index, alias_end_index),
+                            ..AliasExpr::new(value, alias)
+                        },
+                        alias_end_index,
+                    )),
+                    Err(err) => Err(err),
                 },
-                None => Ok((AliasExpr::new_without_alias(value), value_end_index)),
+                // lexers is end or no AS
+                _ => {
+                    source.expect(value_end_index, &["AS"]);
+                    Ok((
+                        AliasExpr {
+                            span: source.span(index, value_end_index),
+                            ..AliasExpr::new_without_alias(value)
+                        },
+                        value_end_index,
+                    ))
+                }
             },
             Err(err) => Err(err),
         }
     }
 }
 
-impl<T> AliasExpr<T>
+impl<T> Spanned for AliasExpr<'_, T>
 where
-    T: Sized + LexerParser,
+    T: Sized,
+{
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a, T> AliasExpr<'a, T>
+where
+    T: Sized,
 {
     /// Creates a new alias expr
     ///
@@ -61,12 +95,13 @@ where
     /// Basic usage:
     ///
     /// ```rust
-    /// let expr = AliasExpr::new(FromItem::NAME(NameExpr::new("table_1")), NameExpr::new("t1"));
+    /// let expr = AliasExpr::new(FromItem::NAME(NameExpr::new("table_1")), IdentExpr::new("t1"));
     /// ```
-    pub fn new(value: T, alias: NameExpr) -> Self {
+    pub fn new(value: T, alias: IdentExpr<'a>) -> Self {
         AliasExpr {
             value,
             alias: Some(alias),
+            span: Span::default(),
         }
     }
 
@@ -80,7 +115,37 @@ where
     /// let expr = AliasExpr::new_without_alias(FromItem::NAME(NameExpr::new("table_1")));
     /// ```
     pub fn new_without_alias(value: T) -> Self {
-        AliasExpr { value, alias: None }
+        AliasExpr {
+            value,
+            alias: None,
+            span: Span::default(),
+        }
+    }
+}
+
+impl<T> ToSql for AliasExpr<'_, T>
+where
+    T: Sized + ToSql,
+{
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        match &self.alias {
+            Some(alias) => format!(
+                "{} {} {}",
+                self.value.to_sql(options),
+                options.keyword("AS"),
+                alias.to_sql(options)
+            ),
+            None => self.value.to_sql(options),
+        }
+    }
+}
+
+impl<T> fmt::Display for AliasExpr<'_, T>
+where
+    T: Sized + ToSql,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
     }
 }
 
@@ -89,9 +154,10 @@ mod tests {
     use rsdb::Named;
 
     use crate::sql::{
-        expr::{alias::AliasExpr, name::NameExpr},
+        expr::{alias::AliasExpr, ident::IdentExpr, name::NameExpr},
         lexer::{lexer::Lexer, mat::LexerMatch},
         parser::{LexerParser, SyntaxPattern},
+        span::eq_ignore_span,
     };
 
     #[test]
@@ -101,16 +167,16 @@ mod tests {
 
     #[test]
     fn it_new() {
-        let expr = AliasExpr::new(NameExpr::new("726d"), NameExpr::new("85f5"));
-        assert_eq!(expr.value.value, "726d");
+        let expr = AliasExpr::new(NameExpr::new("726d"), IdentExpr::new("85f5"));
+        assert_eq!(expr.value, NameExpr::new("726d"));
         assert!(matches!(expr.alias, Some(name) if name.value == "85f5"));
     }
 
     #[test]
     fn it_build_without_alias() {
         let expr = AliasExpr::new_without_alias(NameExpr::new("87c5"));
-        assert_eq!(expr.value.value, "87c5");
-        assert!(matches!(expr.alias, None));
+        assert_eq!(expr.value, NameExpr::new("87c5"));
+        assert!(expr.alias.is_none());
     }
 
     #[test]
@@ -131,7 +197,7 @@ mod tests {
         );
         assert!(matches!(
             AliasExpr::<NameExpr>::parse(&source, 0),
-            Ok((expr, index)) if expr.value.value == "9a42" && matches!(expr.alias, None) && index == 1
+            Ok((expr, index)) if eq_ignore_span(&expr.value, &NameExpr::new("9a42")) && expr.alias.is_none() && index == 1
         ));
         // no AS in lexers
         source = SyntaxPattern::new(
@@ -140,7 +206,7 @@ mod tests {
         );
         assert!(matches!(
             AliasExpr::<NameExpr>::parse(&source, 0),
-            Ok((expr, index)) if expr.value.value == "b579" && matches!(expr.alias, None) && index == 1
+            Ok((expr, index)) if eq_ignore_span(&expr.value, &NameExpr::new("b579")) && expr.alias.is_none() && index == 1
         ));
         // lexers ends with AS
         source = SyntaxPattern::new(
@@ -152,9 +218,9 @@ mod tests {
         );
         assert!(matches!(
             AliasExpr::<NameExpr>::parse(&source, 0),
-            Err(err) if err.cause == "missing name expression"
+            Err(err) if err.cause == "missing identifier"
         ));
-        // no name expression follows AS
+        // no identifier follows AS
         source = SyntaxPattern::new(
             "7888 AS SELECT",
             vec![
@@ -165,7 +231,7 @@ mod tests {
         );
         assert!(matches!(
             AliasExpr::<NameExpr>::parse(&source, 0),
-            Err(err) if err.cause == "missing name expression"
+            Err(err) if err.cause == "missing identifier"
         ));
         // contain alias expression
         source = SyntaxPattern::new(
@@ -178,7 +244,7 @@ mod tests {
         );
         assert!(matches!(
             AliasExpr::<NameExpr>::parse(&source, 0),
-            Ok((expr, index)) if expr.value.value == "f9b8" && matches!(&expr.alias, Some(name_expr) if name_expr.value == "0a54") && index == 3
+            Ok((expr, index)) if eq_ignore_span(&expr.value, &NameExpr::new("f9b8")) && eq_ignore_span(&expr.alias, &Some(IdentExpr::new("0a54"))) && index == 3
         ));
     }
 }
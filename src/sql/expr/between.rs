@@ -0,0 +1,108 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    format::{fmt_sql, FormatOptions, ToSql},
+    span::{Span, Spanned},
+};
+
+use super::expr::Expr;
+
+/// [NOT] BETWEEN Expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BetweenExpr<'a> {
+    pub value: Box<Expr<'a>>,
+    pub low: Box<Expr<'a>>,
+    pub high: Box<Expr<'a>>,
+    pub negated: bool,
+    pub span: Span,
+}
+
+impl Named for BetweenExpr<'_> {
+    const NAMED: &'static str = "between expression";
+}
+
+impl Spanned for BetweenExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> BetweenExpr<'a> {
+    /// Creates a new between expr, `negated` for NOT BETWEEN
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = BetweenExpr::new(
+    ///     Expr::NAME(NameExpr::new("a")),
+    ///     Expr::NAME(NameExpr::new("b")),
+    ///     Expr::NAME(NameExpr::new("c")),
+    ///     false,
+    /// );
+    /// ```
+    pub fn new(value: Expr<'a>, low: Expr<'a>, high: Expr<'a>, negated: bool) -> Self {
+        BetweenExpr {
+            value: Box::new(value),
+            low: Box::new(low),
+            high: Box::new(high),
+            negated,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for BetweenExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let keyword = match self.negated {
+            true => "NOT BETWEEN",
+            false => "BETWEEN",
+        };
+        format!(
+            "{} {} {} {} {}",
+            self.value.to_sql_wrapped(options, 7),
+            options.keyword(keyword),
+            self.low.to_sql_wrapped(options, 7),
+            options.keyword("AND"),
+            self.high.to_sql_wrapped(options, 7)
+        )
+    }
+}
+
+impl fmt::Display for BetweenExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::expr::{expr::Expr, name::NameExpr};
+
+    use super::BetweenExpr;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(BetweenExpr::NAMED, "between expression");
+    }
+
+    #[test]
+    fn it_new() {
+        let expr = BetweenExpr::new(
+            Expr::NAME(NameExpr::new("a3f0")),
+            Expr::NAME(NameExpr::new("1b2c")),
+            Expr::NAME(NameExpr::new("9e8d")),
+            false,
+        );
+        assert_eq!(*expr.value, Expr::NAME(NameExpr::new("a3f0")));
+        assert_eq!(*expr.low, Expr::NAME(NameExpr::new("1b2c")));
+        assert_eq!(*expr.high, Expr::NAME(NameExpr::new("9e8d")));
+        assert!(!expr.negated);
+    }
+}
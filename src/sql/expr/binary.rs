@@ -0,0 +1,185 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    format::{fmt_sql, FormatOptions, ToSql},
+    span::{Span, Spanned},
+};
+
+use super::expr::Expr;
+
+/// Binary operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BinaryOp {
+    EQ,
+    NE,
+    LT,
+    LE,
+    GT,
+    GE,
+    ADD,
+    SUB,
+    MUL,
+    DIV,
+    MOD,
+    CONCAT,
+    AND,
+    OR,
+}
+
+impl BinaryOp {
+    /// Binding strength, see `Expr::precedence`
+    pub fn precedence(&self) -> usize {
+        match self {
+            Self::OR => 1,
+            Self::AND => 2,
+            Self::EQ | Self::NE | Self::LT | Self::LE | Self::GT | Self::GE => 5,
+            Self::CONCAT => 7,
+            Self::ADD | Self::SUB => 8,
+            Self::MUL | Self::DIV | Self::MOD => 9,
+        }
+    }
+
+    /// Whether the operator compares its operands, comparisons do not chain
+    pub fn is_comparison(&self) -> bool {
+        self.precedence() == 5
+    }
+}
+
+impl NamedEnum for BinaryOp {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::EQ => "=",
+            Self::NE => "<>",
+            Self::LT => "<",
+            Self::LE => "<=",
+            Self::GT => ">",
+            Self::GE => ">=",
+            Self::ADD => "+",
+            Self::SUB => "-",
+            Self::MUL => "*",
+            Self::DIV => "/",
+            Self::MOD => "%",
+            Self::CONCAT => "||",
+            Self::AND => "AND",
+            Self::OR => "OR",
+        }
+    }
+}
+
+/// Binary Expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BinaryExpr<'a> {
+    pub left: Box<Expr<'a>>,
+    pub op: BinaryOp,
+    pub right: Box<Expr<'a>>,
+    pub span: Span,
+}
+
+impl Named for BinaryExpr<'_> {
+    const NAMED: &'static str = "binary expression";
+}
+
+impl Spanned for BinaryExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> BinaryExpr<'a> {
+    /// Creates a new binary expr
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = BinaryExpr::new(
+    ///     Expr::NAME(NameExpr::new("a")),
+    ///     BinaryOp::EQ,
+    ///     Expr::NAME(NameExpr::new("b")),
+    /// );
+    /// ```
+    pub fn new(left: Expr<'a>, op: BinaryOp, right: Expr<'a>) -> Self {
+        BinaryExpr {
+            left: Box::new(left),
+            op,
+            right: Box::new(right),
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for BinaryExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let precedence = self.op.precedence();
+        // comparisons do not chain, `(a = b) = c` keeps its parentheses
+        let left_precedence = match self.op.is_comparison() {
+            true => precedence + 1,
+            false => precedence,
+        };
+        let op = match self.op {
+            BinaryOp::AND | BinaryOp::OR => options.keyword(self.op.name()),
+            _ => self.op.name().to_owned(),
+        };
+        format!(
+            "{} {} {}",
+            self.left.to_sql_wrapped(options, left_precedence),
+            op,
+            self.right.to_sql_wrapped(options, precedence + 1)
+        )
+    }
+}
+
+impl fmt::Display for BinaryExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::expr::{expr::Expr, name::NameExpr};
+
+    use super::{BinaryExpr, BinaryOp};
+
+    #[test]
+    fn it_name() {
+        assert_eq!(BinaryExpr::NAMED, "binary expression");
+        assert_eq!(BinaryOp::EQ.name(), "=");
+        assert_eq!(BinaryOp::NE.name(), "<>");
+        assert_eq!(BinaryOp::LT.name(), "<");
+        assert_eq!(BinaryOp::LE.name(), "<=");
+        assert_eq!(BinaryOp::GT.name(), ">");
+        assert_eq!(BinaryOp::GE.name(), ">=");
+        assert_eq!(BinaryOp::MOD.name(), "%");
+        assert_eq!(BinaryOp::CONCAT.name(), "||");
+        assert_eq!(BinaryOp::AND.name(), "AND");
+        assert_eq!(BinaryOp::OR.name(), "OR");
+    }
+
+    #[test]
+    fn it_precedence() {
+        assert!(BinaryOp::MUL.precedence() > BinaryOp::ADD.precedence());
+        assert!(BinaryOp::SUB.precedence() > BinaryOp::CONCAT.precedence());
+        assert!(BinaryOp::CONCAT.precedence() > BinaryOp::EQ.precedence());
+        assert!(BinaryOp::GE.is_comparison() && !BinaryOp::AND.is_comparison());
+    }
+
+    #[test]
+    fn it_new() {
+        let expr = BinaryExpr::new(
+            Expr::NAME(NameExpr::new("5c1e")),
+            BinaryOp::LT,
+            Expr::NAME(NameExpr::new("09ab")),
+        );
+        assert_eq!(*expr.left, Expr::NAME(NameExpr::new("5c1e")));
+        assert_eq!(expr.op, BinaryOp::LT);
+        assert_eq!(*expr.right, Expr::NAME(NameExpr::new("09ab")));
+    }
+}
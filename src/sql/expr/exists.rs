@@ -0,0 +1,74 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    format::{fmt_sql, FormatOptions, ToSql},
+    span::{Span, Spanned},
+};
+
+use super::subquery::SubqueryExpr;
+
+/// EXISTS (SELECT ...) Expression, `NOT EXISTS` is a `NOT` of it
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExistsExpr<'a> {
+    pub subquery: SubqueryExpr<'a>,
+    pub span: Span,
+}
+
+impl Named for ExistsExpr<'_> {
+    const NAMED: &'static str = "exists expression";
+}
+
+impl Spanned for ExistsExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> ExistsExpr<'a> {
+    /// Creates a new exists expr
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = ExistsExpr::new(subquery);
+    /// ```
+    pub fn new(subquery: SubqueryExpr<'a>) -> Self {
+        ExistsExpr {
+            subquery,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for ExistsExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        format!(
+            "{} {}",
+            options.keyword("EXISTS"),
+            self.subquery.to_sql(options)
+        )
+    }
+}
+
+impl fmt::Display for ExistsExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use super::ExistsExpr;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(ExistsExpr::NAMED, "exists expression");
+    }
+}
@@ -0,0 +1,900 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    err::SyntaxError,
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, LexerStart, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+use super::{
+    aggregate::AggregateExpr,
+    between::BetweenExpr,
+    binary::{BinaryExpr, BinaryOp},
+    exists::ExistsExpr,
+    in_list::InListExpr,
+    in_subquery::InSubqueryExpr,
+    is_distinct::IsDistinctExpr,
+    is_null::IsNullExpr,
+    items::ItemsExpr,
+    like::{LikeExpr, LikeOp},
+    literal::LiteralExpr,
+    name::NameExpr,
+    param::ParamExpr,
+    quantified::{QuantifiedExpr, Quantifier},
+    subquery::SubqueryExpr,
+    unary::{UnaryExpr, UnaryOp},
+};
+
+/// Expression
+///
+/// Precedence from lowest to highest: `OR`, `AND`, `NOT`, `IS`, comparison,
+/// `[NOT] BETWEEN` / `[NOT] IN` / `[NOT] LIKE`, `||`, `+ -`, `* / %`, unary `-`, operand.
+/// `IS`, comparisons, `BETWEEN`, `IN` and `LIKE` do not chain, the others are left associative.
+/// A subquery may be an operand, the right side of `[NOT] IN` or of a comparison
+/// quantified by `ANY` or `ALL`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum Expr<'a> {
+    NAME(NameExpr<'a>),
+    LITERAL(LiteralExpr<'a>),
+    PARAM(ParamExpr<'a>),
+    AGGREGATE(AggregateExpr<'a>),
+    UNARY(UnaryExpr<'a>),
+    BINARY(BinaryExpr<'a>),
+    ISNULL(IsNullExpr<'a>),
+    ISDISTINCT(IsDistinctExpr<'a>),
+    BETWEEN(BetweenExpr<'a>),
+    INLIST(InListExpr<'a>),
+    LIKE(LikeExpr<'a>),
+    SUBQUERY(SubqueryExpr<'a>),
+    EXISTS(ExistsExpr<'a>),
+    INSUBQUERY(InSubqueryExpr<'a>),
+    QUANTIFIED(QuantifiedExpr<'a>),
+}
+
+impl Named for Expr<'_> {
+    const NAMED: &'static str = "expression";
+}
+
+impl LexerStart for Expr<'_> {
+    const START: &'static [&'static str] =
+        &["identifier", "literal", "parameter", "'('", "NOT", "'-'"];
+}
+
+impl NamedEnum for Expr<'_> {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::NAME(_) => NameExpr::NAMED,
+            Self::LITERAL(_) => LiteralExpr::NAMED,
+            Self::PARAM(_) => ParamExpr::NAMED,
+            Self::AGGREGATE(_) => AggregateExpr::NAMED,
+            Self::UNARY(_) => UnaryExpr::NAMED,
+            Self::BINARY(_) => BinaryExpr::NAMED,
+            Self::ISNULL(_) => IsNullExpr::NAMED,
+            Self::ISDISTINCT(_) => IsDistinctExpr::NAMED,
+            Self::BETWEEN(_) => BetweenExpr::NAMED,
+            Self::INLIST(_) => InListExpr::NAMED,
+            Self::LIKE(_) => LikeExpr::NAMED,
+            Self::SUBQUERY(_) => SubqueryExpr::NAMED,
+            Self::EXISTS(_) => ExistsExpr::NAMED,
+            Self::INSUBQUERY(_) => InSubqueryExpr::NAMED,
+            Self::QUANTIFIED(_) => QuantifiedExpr::NAMED,
+        }
+    }
+}
+
+impl<'a> LexerParser<'a> for Expr<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        Self::parse_precedence(source, index, 1)
+    }
+}
+
+impl Spanned for Expr<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::NAME(expr) => expr.span(),
+            Self::LITERAL(expr) => expr.span(),
+            Self::PARAM(expr) => expr.span(),
+            Self::AGGREGATE(expr) => expr.span(),
+            Self::UNARY(expr) => expr.span(),
+            Self::BINARY(expr) => expr.span(),
+            Self::ISNULL(expr) => expr.span(),
+            Self::ISDISTINCT(expr) => expr.span(),
+            Self::BETWEEN(expr) => expr.span(),
+            Self::INLIST(expr) => expr.span(),
+            Self::LIKE(expr) => expr.span(),
+            Self::SUBQUERY(expr) => expr.span(),
+            Self::EXISTS(expr) => expr.span(),
+            Self::INSUBQUERY(expr) => expr.span(),
+            Self::QUANTIFIED(expr) => expr.span(),
+        }
+    }
+}
+
+impl<'a> Expr<'a> {
+    /// Binding strength, from `OR` (1), `AND` (2), `NOT` (3), `IS` (4), comparisons (5),
+    /// `BETWEEN` / `IN` / `LIKE` (6), `||` (7), `+ -` (8), `* / %` (9), unary `-` (10)
+    /// to operands (11)
+    pub fn precedence(&self) -> usize {
+        match self {
+            Self::NAME(_)
+            | Self::LITERAL(_)
+            | Self::PARAM(_)
+            | Self::AGGREGATE(_)
+            | Self::SUBQUERY(_)
+            | Self::EXISTS(_) => 11,
+            Self::ISNULL(_) | Self::ISDISTINCT(_) => 4,
+            Self::QUANTIFIED(_) => 5,
+            Self::BETWEEN(_) | Self::INLIST(_) | Self::INSUBQUERY(_) | Self::LIKE(_) => 6,
+            Self::UNARY(expr) => expr.op.precedence(),
+            Self::BINARY(expr) => expr.op.precedence(),
+        }
+    }
+
+    /// Writes the expression, in parentheses if it binds weaker than `precedence`
+    pub fn to_sql_wrapped(&self, options: &FormatOptions, precedence: usize) -> String {
+        match self.precedence() < precedence {
+            true => format!("({})", self.to_sql(options)),
+            false => self.to_sql(options),
+        }
+    }
+
+    /// Parses the operators which bind at least as tight as `precedence`, the right
+    /// operand of an operator only takes the operators binding tighter than it
+    fn parse_precedence(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+        precedence: usize,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        let (mut expr, mut end_index) = Self::parse_prefix(source, index)?;
+        // binding strength of `expr` as written, a parenthesized expression is an operand
+        let mut expr_precedence = match source.items.get(index) {
+            Some(Lexer::NOT(_)) => UnaryOp::NOT.precedence(),
+            Some(Lexer::MINUS(_)) => UnaryOp::NEG.precedence(),
+            _ => 11,
+        };
+        loop {
+            let lexer = source.items.get(end_index);
+            let op = Self::binary_op(lexer);
+            let op_precedence = match (op, lexer) {
+                (Some(op), _) => op.precedence(),
+                (None, Some(Lexer::IS(_))) => 4,
+                (
+                    None,
+                    Some(
+                        Lexer::NOT(_)
+                        | Lexer::BETWEEN(_)
+                        | Lexer::IN(_)
+                        | Lexer::LIKE(_)
+                        | Lexer::ILIKE(_)
+                        | Lexer::SIMILAR(_),
+                    ),
+                ) => 6,
+                _ => {
+                    source.expect(end_index, &["operator"]);
+                    break;
+                }
+            };
+            // only `AND`, `OR`, `||` and the arithmetic operators chain, `a = b = c` stops
+            // after `a = b`
+            let chains = matches!(op_precedence, 1 | 2 | 7..=9);
+            if op_precedence < precedence
+                || op_precedence > expr_precedence
+                || (op_precedence == expr_precedence && !chains)
+            {
+                break;
+            }
+            (expr, end_index) = match (op, lexer) {
+                (Some(op), _) => Self::parse_binary(source, index, end_index, expr, op)?,
+                (None, Some(Lexer::IS(_))) => Self::parse_is(source, index, end_index + 1, expr)?,
+                // NOT BETWEEN, NOT IN, NOT LIKE
+                (None, Some(Lexer::NOT(_))) => match source.items.get(end_index + 1) {
+                    Some(Lexer::BETWEEN(_)) => {
+                        Self::parse_between(source, index, end_index + 2, expr, true)?
+                    }
+                    Some(Lexer::IN(_)) => {
+                        Self::parse_in_list(source, index, end_index + 2, expr, true)?
+                    }
+                    Some(Lexer::LIKE(_) | Lexer::ILIKE(_) | Lexer::SIMILAR(_)) => {
+                        Self::parse_like(source, index, end_index + 1, expr, true)?
+                    }
+                    _ => {
+                        return Err(source.missing(
+                            end_index + 1,
+                            "BETWEEN, IN or LIKE",
+                            &["BETWEEN", "IN", "LIKE", "ILIKE", "SIMILAR"],
+                        ))
+                    }
+                },
+                (None, Some(Lexer::BETWEEN(_))) => {
+                    Self::parse_between(source, index, end_index + 1, expr, false)?
+                }
+                (None, Some(Lexer::IN(_))) => {
+                    Self::parse_in_list(source, index, end_index + 1, expr, false)?
+                }
+                _ => Self::parse_like(source, index, end_index, expr, false)?,
+            };
+            expr_precedence = op_precedence;
+        }
+        Ok((expr, end_index))
+    }
+
+    /// The binary operator of the lexer, `*` is a multiplication after an operand
+    fn binary_op(lexer: Option<&Lexer<'_>>) -> Option<BinaryOp> {
+        match lexer {
+            Some(Lexer::OR(_)) => Some(BinaryOp::OR),
+            Some(Lexer::AND(_)) => Some(BinaryOp::AND),
+            Some(Lexer::EQ(_)) => Some(BinaryOp::EQ),
+            Some(Lexer::NE(_)) => Some(BinaryOp::NE),
+            Some(Lexer::LT(_)) => Some(BinaryOp::LT),
+            Some(Lexer::LE(_)) => Some(BinaryOp::LE),
+            Some(Lexer::GT(_)) => Some(BinaryOp::GT),
+            Some(Lexer::GE(_)) => Some(BinaryOp::GE),
+            Some(Lexer::CONCAT(_)) => Some(BinaryOp::CONCAT),
+            Some(Lexer::PLUS(_)) => Some(BinaryOp::ADD),
+            Some(Lexer::MINUS(_)) => Some(BinaryOp::SUB),
+            Some(Lexer::STAR(_)) => Some(BinaryOp::MUL),
+            Some(Lexer::SLASH(_)) => Some(BinaryOp::DIV),
+            Some(Lexer::PERCENT(_)) => Some(BinaryOp::MOD),
+            _ => None,
+        }
+    }
+
+    /// NOT expr, -expr or an operand
+    fn parse_prefix(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        let op = match source.items.get(index) {
+            Some(Lexer::NOT(_)) => UnaryOp::NOT,
+            Some(Lexer::MINUS(_)) => UnaryOp::NEG,
+            _ => {
+                source.expect(index, &["NOT", "'-'"]);
+                return Self::parse_operand(source, index);
+            }
+        };
+        let (expr, end_index) = Self::parse_precedence(source, index + 1, op.precedence())?;
+        Ok((
+            Expr::UNARY(UnaryExpr {
+                span: source.span(index, end_index),
+                ..UnaryExpr::new(op, expr)
+            }),
+            end_index,
+        ))
+    }
+
+    /// op expr or op {ANY | ALL} (SELECT ...) after the left operand, `index` is the
+    /// index of the operator and `start` the index of the left operand
+    fn parse_binary(
+        source: &SyntaxPattern<'a>,
+        start: usize,
+        index: usize,
+        left: Expr<'a>,
+        op: BinaryOp,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        if op.is_comparison() {
+            let quantifier = match source.items.get(index + 1) {
+                Some(Lexer::ANY(_)) => Some(Quantifier::ANY),
+                Some(Lexer::ALL(_)) => Some(Quantifier::ALL),
+                _ => {
+                    source.expect(index + 1, &["ANY", "ALL"]);
+                    None
+                }
+            };
+            if let Some(quantifier) = quantifier {
+                let (subquery, end_index) = SubqueryExpr::parse(source, index + 2)?;
+                return Ok((
+                    Expr::QUANTIFIED(QuantifiedExpr {
+                        span: source.span(start, end_index),
+                        ..QuantifiedExpr::new(left, op, quantifier, subquery)
+                    }),
+                    end_index,
+                ));
+            }
+        }
+        let (right, end_index) = Self::parse_precedence(source, index + 1, op.precedence() + 1)?;
+        Ok((
+            Expr::BINARY(BinaryExpr {
+                span: source.span(start, end_index),
+                ..BinaryExpr::new(left, op, right)
+            }),
+            end_index,
+        ))
+    }
+
+    /// [NOT] NULL or [NOT] DISTINCT FROM expr after IS, `start` is the index of the value
+    fn parse_is(
+        source: &SyntaxPattern<'a>,
+        start: usize,
+        index: usize,
+        value: Expr<'a>,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        let (negated, index) = match source.items.get(index) {
+            Some(Lexer::NOT(_)) => (true, index + 1),
+            _ => {
+                source.expect(index, &["NOT"]);
+                (false, index)
+            }
+        };
+        match (source.items.get(index), source.items.get(index + 1)) {
+            (Some(Lexer::NULL(_)), _) => Ok((
+                Expr::ISNULL(IsNullExpr {
+                    span: source.span(start, index + 1),
+                    ..IsNullExpr::new(value, negated)
+                }),
+                index + 1,
+            )),
+            (Some(Lexer::DISTINCT(_)), Some(Lexer::FROM(_))) => {
+                let (right, end_index) = Self::parse_precedence(source, index + 2, 5)?;
+                Ok((
+                    Expr::ISDISTINCT(IsDistinctExpr {
+                        span: source.span(start, end_index),
+                        ..IsDistinctExpr::new(value, right, negated)
+                    }),
+                    end_index,
+                ))
+            }
+            (Some(Lexer::DISTINCT(_)), _) => Err(source.missing(index + 1, "FROM", &["FROM"])),
+            _ => Err(source.missing(index, "NULL or DISTINCT", &["NULL", "DISTINCT"])),
+        }
+    }
+
+    /// {LIKE | ILIKE | SIMILAR TO} pattern, `index` is the index of the operator and
+    /// `start` the index of the value
+    fn parse_like(
+        source: &SyntaxPattern<'a>,
+        start: usize,
+        index: usize,
+        value: Expr<'a>,
+        negated: bool,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        let (op, pattern_index) = match (source.items.get(index), source.items.get(index + 1)) {
+            (Some(Lexer::LIKE(_)), _) => (LikeOp::LIKE, index + 1),
+            (Some(Lexer::ILIKE(_)), _) => (LikeOp::ILIKE, index + 1),
+            (Some(Lexer::SIMILAR(_)), Some(Lexer::TO(_))) => (LikeOp::SIMILARTO, index + 2),
+            (Some(Lexer::SIMILAR(_)), _) => return Err(source.missing(index + 1, "TO", &["TO"])),
+            _ => return Err(source.missing(index, "LIKE", &["LIKE", "ILIKE", "SIMILAR"])),
+        };
+        let (pattern, end_index) = Self::parse_precedence(source, pattern_index, 7)?;
+        Ok((
+            Expr::LIKE(LikeExpr {
+                span: source.span(start, end_index),
+                ..LikeExpr::new(value, op, pattern, negated)
+            }),
+            end_index,
+        ))
+    }
+
+    /// low AND high after [NOT] BETWEEN, `start` is the index of the value
+    fn parse_between(
+        source: &SyntaxPattern<'a>,
+        start: usize,
+        index: usize,
+        value: Expr<'a>,
+        negated: bool,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        // the bounds take arithmetic only, the AND belongs to BETWEEN
+        let (low, low_end_index) = Self::parse_precedence(source, index, 7)?;
+        match source.items.get(low_end_index) {
+            Some(Lexer::AND(_)) => match Self::parse_precedence(source, low_end_index + 1, 7) {
+                Ok((high, high_end_index)) => Ok((
+                    Expr::BETWEEN(BetweenExpr {
+                        span: source.span(start, high_end_index),
+                        ..BetweenExpr::new(value, low, high, negated)
+                    }),
+                    high_end_index,
+                )),
+                Err(err) => Err(err),
+            },
+            _ => Err(source.missing(low_end_index, "AND", &["AND"])),
+        }
+    }
+
+    /// (expr, ...) or (SELECT ...) after [NOT] IN, `start` is the index of the value
+    fn parse_in_list(
+        source: &SyntaxPattern<'a>,
+        start: usize,
+        index: usize,
+        value: Expr<'a>,
+        negated: bool,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        if SubqueryExpr::is_start(source, index) {
+            let (subquery, end_index) = SubqueryExpr::parse(source, index)?;
+            return Ok((
+                Expr::INSUBQUERY(InSubqueryExpr {
+                    span: source.span(start, end_index),
+                    ..InSubqueryExpr::new(value, subquery, negated)
+                }),
+                end_index,
+            ));
+        }
+        match source.items.get(index) {
+            Some(Lexer::LPAREN(_)) => {
+                let (list, list_end_index) = ItemsExpr::parse(source, index + 1)?;
+                if let Some(err) = list.min_len_check(source, index + 1, 1) {
+                    return Err(err);
+                }
+                match source.items.get(list_end_index) {
+                    Some(Lexer::RPAREN(_)) => Ok((
+                        Expr::INLIST(InListExpr {
+                            span: source.span(start, list_end_index + 1),
+                            ..InListExpr::new(value, list, negated)
+                        }),
+                        list_end_index + 1,
+                    )),
+                    _ => Err(source.missing(list_end_index, ")", &["')'"])),
+                }
+            }
+            _ => Err(source.missing(index, "(", &["'('"])),
+        }
+    }
+
+    /// name, literal, parameter, aggregate call, (expr), (SELECT ...) or EXISTS (SELECT ...)
+    fn parse_operand(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        match source.items.get(index) {
+            Some(Lexer::NAME(_)) if AggregateExpr::is_start(source, index) => {
+                match AggregateExpr::parse(source, index) {
+                    Ok((expr, end_index)) => Ok((Expr::AGGREGATE(expr), end_index)),
+                    Err(err) => Err(err),
+                }
+            }
+            Some(lexer) if lexer.is_ident() => match NameExpr::parse(source, index) {
+                Ok((expr, end_index)) => Ok((Expr::NAME(expr), end_index)),
+                Err(err) => Err(err),
+            },
+            Some(
+                Lexer::INTEGER(_)
+                | Lexer::DECIMAL(_)
+                | Lexer::SCIENTIFIC(_)
+                | Lexer::HEX(_)
+                | Lexer::STRING(_)
+                | Lexer::ESTRING(_),
+            ) => match LiteralExpr::parse(source, index) {
+                Ok((expr, end_index)) => Ok((Expr::LITERAL(expr), end_index)),
+                Err(err) => Err(err),
+            },
+            Some(Lexer::PARAM(_)) => match ParamExpr::parse(source, index) {
+                Ok((expr, end_index)) => Ok((Expr::PARAM(expr), end_index)),
+                Err(err) => Err(err),
+            },
+            Some(Lexer::LPAREN(_)) if SubqueryExpr::is_start(source, index) => {
+                match SubqueryExpr::parse(source, index) {
+                    Ok((expr, end_index)) => Ok((Expr::SUBQUERY(expr), end_index)),
+                    Err(err) => Err(err),
+                }
+            }
+            Some(Lexer::EXISTS(_)) => match SubqueryExpr::parse(source, index + 1) {
+                Ok((subquery, end_index)) => Ok((
+                    Expr::EXISTS(ExistsExpr {
+                        span: source.span(index, end_index),
+                        ..ExistsExpr::new(subquery)
+                    }),
+                    end_index,
+                )),
+                Err(err) => Err(err),
+            },
+            Some(Lexer::LPAREN(_)) => {
+                let (expr, end_index) = Self::parse(source, index + 1)?;
+                match source.items.get(end_index) {
+                    Some(Lexer::RPAREN(_)) => Ok((expr, end_index + 1)),
+                    _ => Err(source.missing(end_index, ")", &["')'"])),
+                }
+            }
+            _ => Err(source.missing(
+                index,
+                Self::NAMED,
+                &["identifier", "literal", "parameter", "'('"],
+            )),
+        }
+    }
+}
+
+impl ToSql for Expr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        match self {
+            Self::NAME(expr) => expr.to_sql(options),
+            Self::LITERAL(expr) => expr.to_sql(options),
+            Self::PARAM(expr) => expr.to_sql(options),
+            Self::AGGREGATE(expr) => expr.to_sql(options),
+            Self::UNARY(expr) => expr.to_sql(options),
+            Self::BINARY(expr) => expr.to_sql(options),
+            Self::ISNULL(expr) => expr.to_sql(options),
+            Self::ISDISTINCT(expr) => expr.to_sql(options),
+            Self::BETWEEN(expr) => expr.to_sql(options),
+            Self::INLIST(expr) => expr.to_sql(options),
+            Self::LIKE(expr) => expr.to_sql(options),
+            Self::SUBQUERY(expr) => expr.to_sql(options),
+            Self::EXISTS(expr) => expr.to_sql(options),
+            Self::INSUBQUERY(expr) => expr.to_sql(options),
+            Self::QUANTIFIED(expr) => expr.to_sql(options),
+        }
+    }
+}
+
+impl fmt::Display for Expr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::{
+        expr::{
+            between::BetweenExpr,
+            binary::{BinaryExpr, BinaryOp},
+            in_list::InListExpr,
+            is_distinct::IsDistinctExpr,
+            is_null::IsNullExpr,
+            items::ItemsExpr,
+            like::{LikeExpr, LikeOp},
+            literal::LiteralExpr,
+            name::NameExpr,
+            quantified::Quantifier,
+            unary::{UnaryExpr, UnaryOp},
+        },
+        lexer::pattern::LexerPattern,
+        parser::{LexerParser, SyntaxPattern},
+        span::{eq_ignore_span, Span},
+    };
+
+    use super::Expr;
+
+    fn parse(text: &str) -> Result<(Expr<'_>, usize), crate::sql::err::SyntaxError<'_>> {
+        let source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        Expr::parse(&source, 0)
+    }
+
+    fn name(value: &str) -> Expr<'_> {
+        Expr::NAME(NameExpr::new(value))
+    }
+
+    fn binary<'a>(left: Expr<'a>, op: BinaryOp, right: Expr<'a>) -> Expr<'a> {
+        Expr::BINARY(BinaryExpr::new(left, op, right))
+    }
+
+    fn integer(value: &str) -> Expr<'_> {
+        Expr::LITERAL(LiteralExpr::INTEGER(value.into(), Span::default()))
+    }
+
+    #[test]
+    fn it_name() {
+        assert_eq!(Expr::NAMED, "expression");
+        assert_eq!(name("").name(), "name expression");
+        assert_eq!(
+            Expr::LITERAL(LiteralExpr::INTEGER("".into(), Span::default())).name(),
+            "literal expression"
+        );
+        assert_eq!(
+            Expr::UNARY(UnaryExpr::new(UnaryOp::NOT, name(""))).name(),
+            "unary expression"
+        );
+        assert_eq!(
+            binary(name(""), BinaryOp::EQ, name("")).name(),
+            "binary expression"
+        );
+        assert_eq!(
+            Expr::ISNULL(IsNullExpr::new(name(""), false)).name(),
+            "is null expression"
+        );
+        assert_eq!(
+            Expr::BETWEEN(BetweenExpr::new(name(""), name(""), name(""), false)).name(),
+            "between expression"
+        );
+        assert_eq!(
+            Expr::INLIST(InListExpr::new(name(""), ItemsExpr::new(vec![]), false)).name(),
+            "in list expression"
+        );
+        assert_eq!(
+            Expr::ISDISTINCT(IsDistinctExpr::new(name(""), name(""), false)).name(),
+            "is distinct from expression"
+        );
+        assert_eq!(
+            Expr::LIKE(LikeExpr::new(name(""), LikeOp::LIKE, name(""), false)).name(),
+            "like expression"
+        );
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        assert!(matches!(
+            parse(""),
+            Err(err) if err.cause == "missing expression"
+        ));
+        // name
+        assert!(matches!(
+            parse("a1b2"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &name("a1b2")) && index == 1
+        ));
+        // literal
+        assert!(matches!(
+            parse("a = 'b' AND c <> 1.5"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &binary(
+                binary(name("a"), BinaryOp::EQ, Expr::LITERAL(LiteralExpr::STRING("b".into(), Span::default()))),
+                BinaryOp::AND,
+                binary(name("c"), BinaryOp::NE, Expr::LITERAL(LiteralExpr::DECIMAL("1.5".into(), Span::default()))),
+            )) && index == 7
+        ));
+        // comparisons
+        for (text, op) in [
+            ("a = b", BinaryOp::EQ),
+            ("a <> b", BinaryOp::NE),
+            ("a != b", BinaryOp::NE),
+            ("a < b", BinaryOp::LT),
+            ("a <= b", BinaryOp::LE),
+            ("a > b", BinaryOp::GT),
+            ("a >= b", BinaryOp::GE),
+        ] {
+            assert!(matches!(
+                parse(text),
+                Ok((expr, index)) if eq_ignore_span(&expr, &binary(name("a"), op, name("b"))) && index == 3
+            ));
+        }
+        // missing right operand
+        assert!(matches!(
+            parse("a ="),
+            Err(err) if err.cause == "missing expression"
+        ));
+        // AND binds tighter than OR
+        assert!(matches!(
+            parse("a = b OR c = d AND e = f"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &binary(
+                binary(name("a"), BinaryOp::EQ, name("b")),
+                BinaryOp::OR,
+                binary(
+                    binary(name("c"), BinaryOp::EQ, name("d")),
+                    BinaryOp::AND,
+                    binary(name("e"), BinaryOp::EQ, name("f")),
+                ),
+            )) && index == 11
+        ));
+        // left associative
+        assert!(matches!(
+            parse("a AND b AND c"),
+            Ok((expr, _)) if eq_ignore_span(&expr, &binary(
+                binary(name("a"), BinaryOp::AND, name("b")),
+                BinaryOp::AND,
+                name("c"),
+            ))
+        ));
+        // parentheses
+        assert!(matches!(
+            parse("(a OR b) AND c"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &binary(
+                binary(name("a"), BinaryOp::OR, name("b")),
+                BinaryOp::AND,
+                name("c"),
+            )) && index == 7
+        ));
+        assert!(matches!(
+            parse("(a OR b"),
+            Err(err) if err.cause == "missing )"
+        ));
+        // NOT
+        assert!(matches!(
+            parse("NOT a = b AND c"),
+            Ok((expr, _)) if eq_ignore_span(&expr, &binary(
+                Expr::UNARY(UnaryExpr::new(UnaryOp::NOT, binary(name("a"), BinaryOp::EQ, name("b")))),
+                BinaryOp::AND,
+                name("c"),
+            ))
+        ));
+        // IS [NOT] NULL
+        assert!(matches!(
+            parse("a IS NULL"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &Expr::ISNULL(IsNullExpr::new(name("a"), false))) && index == 3
+        ));
+        assert!(matches!(
+            parse("a is not null"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &Expr::ISNULL(IsNullExpr::new(name("a"), true))) && index == 4
+        ));
+        assert!(matches!(
+            parse("a IS b"),
+            Err(err) if err.cause == "missing NULL or DISTINCT"
+        ));
+        // [NOT] BETWEEN
+        assert!(matches!(
+            parse("a BETWEEN b AND c AND d"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &binary(
+                Expr::BETWEEN(BetweenExpr::new(name("a"), name("b"), name("c"), false)),
+                BinaryOp::AND,
+                name("d"),
+            )) && index == 7
+        ));
+        assert!(matches!(
+            parse("a NOT BETWEEN b AND c"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &Expr::BETWEEN(BetweenExpr::new(name("a"), name("b"), name("c"), true))) && index == 6
+        ));
+        assert!(matches!(
+            parse("a BETWEEN b"),
+            Err(err) if err.cause == "missing AND"
+        ));
+        // [NOT] IN
+        assert!(matches!(
+            parse("a IN (b, c)"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &Expr::INLIST(InListExpr::new(
+                name("a"),
+                ItemsExpr::new(vec![name("b"), name("c")]),
+                false,
+            ))) && index == 7
+        ));
+        assert!(matches!(
+            parse("a NOT IN (b)"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &Expr::INLIST(InListExpr::new(
+                name("a"),
+                ItemsExpr::new(vec![name("b")]),
+                true,
+            ))) && index == 6
+        ));
+        assert!(matches!(
+            parse("a IN b"),
+            Err(err) if err.cause == "missing ("
+        ));
+        assert!(matches!(
+            parse("a IN (b"),
+            Err(err) if err.cause == "missing )"
+        ));
+        assert!(matches!(
+            parse("a IN ()"),
+            Err(err) if err.cause == "missing expression"
+        ));
+        assert!(matches!(
+            parse("a NOT b"),
+            Err(err) if err.cause == "missing BETWEEN, IN or LIKE"
+        ));
+        // subqueries
+        assert!(matches!(
+            parse("(SELECT a FROM t) = 1"),
+            Ok((Expr::BINARY(expr), index)) if matches!(*expr.left, Expr::SUBQUERY(_)) && index == 8
+        ));
+        assert!(matches!(
+            parse("NOT EXISTS (SELECT a FROM t)"),
+            Ok((Expr::UNARY(expr), index)) if matches!(&*expr.value, Expr::EXISTS(exists) if exists.span.range() == (4..28))
+                && index == 8
+        ));
+        assert!(matches!(
+            parse("a NOT IN (SELECT a FROM t)"),
+            Ok((Expr::INSUBQUERY(expr), index)) if eq_ignore_span(&*expr.value, &name("a")) && expr.negated && index == 9
+        ));
+        assert!(matches!(
+            parse("a >= ANY (SELECT a FROM t)"),
+            Ok((Expr::QUANTIFIED(expr), index)) if expr.op == BinaryOp::GE
+                && expr.quantifier == Quantifier::ANY && index == 9
+        ));
+        assert!(matches!(
+            parse("a = ALL (b)"),
+            Err(err) if err.cause == "missing SELECT"
+        ));
+        assert!(matches!(
+            parse("EXISTS a"),
+            Err(err) if err.cause == "missing subquery"
+        ));
+    }
+
+    #[test]
+    fn it_parse_operators() {
+        // arithmetic binds tighter than comparisons
+        assert!(matches!(
+            parse("a + b * c = d"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &binary(
+                binary(name("a"), BinaryOp::ADD, binary(name("b"), BinaryOp::MUL, name("c"))),
+                BinaryOp::EQ,
+                name("d"),
+            )) && index == 7
+        ));
+        assert!(matches!(
+            parse("(a + b) * c % 2"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &binary(
+                binary(binary(name("a"), BinaryOp::ADD, name("b")), BinaryOp::MUL, name("c")),
+                BinaryOp::MOD,
+                integer("2"),
+            )) && index == 9
+        ));
+        assert!(matches!(
+            parse("a - b - c / d"),
+            Ok((expr, _)) if eq_ignore_span(&expr, &binary(
+                binary(name("a"), BinaryOp::SUB, name("b")),
+                BinaryOp::SUB,
+                binary(name("c"), BinaryOp::DIV, name("d")),
+            ))
+        ));
+        // || binds looser than + and tighter than =
+        assert!(matches!(
+            parse("a || b + 1 = c"),
+            Ok((expr, _)) if eq_ignore_span(&expr, &binary(
+                binary(name("a"), BinaryOp::CONCAT, binary(name("b"), BinaryOp::ADD, integer("1"))),
+                BinaryOp::EQ,
+                name("c"),
+            ))
+        ));
+        // negation binds tightest, NOT looser than comparisons
+        assert!(matches!(
+            parse("-a * - -b"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &binary(
+                Expr::UNARY(UnaryExpr::new(UnaryOp::NEG, name("a"))),
+                BinaryOp::MUL,
+                Expr::UNARY(UnaryExpr::new(UnaryOp::NEG, Expr::UNARY(UnaryExpr::new(UnaryOp::NEG, name("b"))))),
+            )) && index == 6
+        ));
+        assert!(matches!(
+            parse("NOT -a < b"),
+            Ok((expr, _)) if eq_ignore_span(&expr, &Expr::UNARY(UnaryExpr::new(
+                UnaryOp::NOT,
+                binary(Expr::UNARY(UnaryExpr::new(UnaryOp::NEG, name("a"))), BinaryOp::LT, name("b")),
+            )))
+        ));
+        assert!(matches!(
+            parse("a * -"),
+            Err(err) if err.cause == "missing expression"
+        ));
+        // comparisons do not chain
+        assert!(matches!(
+            parse("a = b = c"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &binary(name("a"), BinaryOp::EQ, name("b"))) && index == 3
+        ));
+        // [NOT] LIKE, ILIKE and SIMILAR TO
+        assert!(matches!(
+            parse("a LIKE 'b%' AND c"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &binary(
+                Expr::LIKE(LikeExpr::new(
+                    name("a"),
+                    LikeOp::LIKE,
+                    Expr::LITERAL(LiteralExpr::STRING("b%".into(), Span::default())),
+                    false,
+                )),
+                BinaryOp::AND,
+                name("c"),
+            )) && index == 5
+        ));
+        assert!(matches!(
+            parse("a NOT ILIKE b || c"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &Expr::LIKE(LikeExpr::new(
+                name("a"),
+                LikeOp::ILIKE,
+                binary(name("b"), BinaryOp::CONCAT, name("c")),
+                true,
+            ))) && index == 6
+        ));
+        assert!(matches!(
+            parse("a similar to b"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &Expr::LIKE(LikeExpr::new(name("a"), LikeOp::SIMILARTO, name("b"), false)))
+                && index == 4
+        ));
+        assert!(matches!(
+            parse("a SIMILAR b"),
+            Err(err) if err.cause == "missing TO"
+        ));
+        // IS [NOT] DISTINCT FROM
+        assert!(matches!(
+            parse("a IS DISTINCT FROM b + 1"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &Expr::ISDISTINCT(IsDistinctExpr::new(
+                name("a"),
+                binary(name("b"), BinaryOp::ADD, integer("1")),
+                false,
+            ))) && index == 7
+        ));
+        assert!(matches!(
+            parse("a IS NOT DISTINCT FROM b OR c"),
+            Ok((expr, index)) if eq_ignore_span(&expr, &binary(
+                Expr::ISDISTINCT(IsDistinctExpr::new(name("a"), name("b"), true)),
+                BinaryOp::OR,
+                name("c"),
+            )) && index == 8
+        ));
+        assert!(matches!(
+            parse("a IS DISTINCT b"),
+            Err(err) if err.cause == "missing FROM"
+        ));
+    }
+}
@@ -0,0 +1,217 @@
+use std::{borrow::Cow, fmt};
+
+use rsdb::Named;
+
+use crate::sql::{
+    err::SyntaxError,
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, LexerStart, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+/// Identifier Expression
+///
+/// Unquoted identifiers fold to lower case, quoted identifiers (`"Name"` or `` `Name` ``)
+/// keep their case and may be reserved words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdentExpr<'a> {
+    pub value: Cow<'a, str>,
+    pub quoted: bool,
+    pub span: Span,
+}
+
+impl Named for IdentExpr<'_> {
+    const NAMED: &'static str = "identifier";
+}
+
+impl LexerStart for IdentExpr<'_> {
+    const START: &'static [&'static str] = &["identifier"];
+}
+
+impl<'a> LexerParser<'a> for IdentExpr<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>>
+    where
+        Self: Sized,
+    {
+        match source.items.get(index) {
+            Some(Lexer::QUOTED(value)) => Ok((
+                IdentExpr {
+                    value: Self::unquote(value.as_str()),
+                    quoted: true,
+                    span: source.span(index, index + 1),
+                },
+                index + 1,
+            )),
+            // names & non reserved keywords
+            Some(lexer) if lexer.is_ident() => Ok((
+                IdentExpr {
+                    span: source.span(index, index + 1),
+                    ..IdentExpr::new(lexer.value().as_str())
+                },
+                index + 1,
+            )),
+            _ => Err(source.missing(index, Self::NAMED, &["identifier"])),
+        }
+    }
+}
+
+impl Spanned for IdentExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> IdentExpr<'a> {
+    /// Creates a new unquoted identifier, the value is folded to lower case and
+    /// only copied if it has upper case chars
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// assert_eq!(IdentExpr::new("Table_1").value, "table_1");
+    /// ```
+    pub fn new(value: &'a str) -> Self {
+        let value = match value.chars().any(char::is_uppercase) {
+            true => Cow::Owned(value.to_lowercase()),
+            false => Cow::Borrowed(value),
+        };
+        IdentExpr {
+            value,
+            quoted: false,
+            span: Span::default(),
+        }
+    }
+
+    /// Creates a new quoted identifier, the value is kept as is
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// assert_eq!(IdentExpr::new_quoted("Order Details").value, "Order Details");
+    /// ```
+    pub fn new_quoted(value: &'a str) -> Self {
+        IdentExpr {
+            value: Cow::Borrowed(value),
+            quoted: true,
+            span: Span::default(),
+        }
+    }
+
+    /// Strips the quotes of a quoted identifier, a doubled quote stands for itself,
+    /// the text is only copied if it has doubled quotes
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// assert_eq!(IdentExpr::unquote(r#""a""b""#), r#"a"b"#);
+    /// ```
+    pub fn unquote(text: &'a str) -> Cow<'a, str> {
+        let quote = &text[..1];
+        let inner = &text[1..text.len() - 1];
+        match inner.contains(&quote.repeat(2)) {
+            true => Cow::Owned(inner.replace(&quote.repeat(2), quote)),
+            false => Cow::Borrowed(inner),
+        }
+    }
+}
+
+impl ToSql for IdentExpr<'_> {
+    fn to_sql(&self, _options: &FormatOptions) -> String {
+        match self.quoted {
+            true => format!("\"{}\"", self.value.replace('"', "\"\"")),
+            false => self.value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for IdentExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use rsdb::Named;
+
+    use crate::sql::{
+        lexer::{lexer::Lexer, mat::LexerMatch},
+        parser::{LexerParser, SyntaxPattern},
+        span::eq_ignore_span,
+    };
+
+    use super::IdentExpr;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(IdentExpr::NAMED, "identifier");
+    }
+
+    #[test]
+    fn it_new() {
+        assert_eq!(IdentExpr::new("4812").value, "4812");
+        assert_eq!(IdentExpr::new("AbC").value, "abc");
+        assert!(!IdentExpr::new("AbC").quoted);
+        assert_eq!(IdentExpr::new_quoted("AbC").value, "AbC");
+        assert!(IdentExpr::new_quoted("AbC").quoted);
+    }
+
+    #[test]
+    fn it_unquote() {
+        assert_eq!(IdentExpr::unquote(r#""Order Details""#), "Order Details");
+        assert_eq!(IdentExpr::unquote(r#""a""b""#), r#"a"b"#);
+        assert_eq!(IdentExpr::unquote("`us``er`"), "us`er");
+        // the text is borrowed without doubled quotes
+        assert!(matches!(IdentExpr::unquote(r#""ab""#), Cow::Borrowed("ab")));
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            IdentExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing identifier"
+        ));
+        // current lexer is not an identifier
+        source = SyntaxPattern::new("", vec![Lexer::SELECT(LexerMatch::new("SELECT", 0, 5))]);
+        assert!(matches!(
+            IdentExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing identifier"
+        ));
+        // current lexer is an identifier
+        source = SyntaxPattern::new("", vec![Lexer::NAME(LexerMatch::new("8d06", 0, 4))]);
+        assert!(matches!(
+            IdentExpr::parse(&source, 0),
+            Ok((expr, index)) if expr.value == "8d06" && expr.span.range() == (0..4) && index == 1
+                && matches!(expr.value, Cow::Borrowed(_))
+        ));
+        // unquoted identifier is folded
+        source = SyntaxPattern::new("", vec![Lexer::NAME(LexerMatch::new("Ab_C", 0, 4))]);
+        assert!(matches!(
+            IdentExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &IdentExpr::new("ab_c")) && index == 1
+                && matches!(expr.value, Cow::Owned(_))
+        ));
+        // quoted identifier is preserved
+        source = SyntaxPattern::new(
+            "",
+            vec![Lexer::QUOTED(LexerMatch::new_full_match(r#""Select""#))],
+        );
+        assert!(matches!(
+            IdentExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &IdentExpr::new_quoted("Select")) && index == 1
+                && matches!(expr.value, Cow::Borrowed(_))
+        ));
+    }
+}
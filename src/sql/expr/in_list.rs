@@ -0,0 +1,101 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    format::{fmt_sql, FormatOptions, ToSql},
+    span::{Span, Spanned},
+};
+
+use super::{expr::Expr, items::ItemsExpr};
+
+/// [NOT] IN (...) Expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InListExpr<'a> {
+    pub value: Box<Expr<'a>>,
+    pub list: ItemsExpr<Expr<'a>>,
+    pub negated: bool,
+    pub span: Span,
+}
+
+impl Named for InListExpr<'_> {
+    const NAMED: &'static str = "in list expression";
+}
+
+impl Spanned for InListExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> InListExpr<'a> {
+    /// Creates a new in list expr, `negated` for NOT IN
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = InListExpr::new(
+    ///     Expr::NAME(NameExpr::new("a")),
+    ///     ItemsExpr::new(vec![Expr::NAME(NameExpr::new("b"))]),
+    ///     false,
+    /// );
+    /// ```
+    pub fn new(value: Expr<'a>, list: ItemsExpr<Expr<'a>>, negated: bool) -> Self {
+        InListExpr {
+            value: Box::new(value),
+            list,
+            negated,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for InListExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let keyword = match self.negated {
+            true => "NOT IN",
+            false => "IN",
+        };
+        format!(
+            "{} {} ({})",
+            self.value.to_sql_wrapped(options, 7),
+            options.keyword(keyword),
+            self.list.to_sql(options)
+        )
+    }
+}
+
+impl fmt::Display for InListExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::expr::{expr::Expr, items::ItemsExpr, name::NameExpr};
+
+    use super::InListExpr;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(InListExpr::NAMED, "in list expression");
+    }
+
+    #[test]
+    fn it_new() {
+        let expr = InListExpr::new(
+            Expr::NAME(NameExpr::new("4c4d")),
+            ItemsExpr::new(vec![Expr::NAME(NameExpr::new("f00e"))]),
+            true,
+        );
+        assert_eq!(*expr.value, Expr::NAME(NameExpr::new("4c4d")));
+        assert_eq!(expr.list.items, [Expr::NAME(NameExpr::new("f00e"))]);
+        assert!(expr.negated);
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    format::{fmt_sql, FormatOptions, ToSql},
+    span::{Span, Spanned},
+};
+
+use super::{expr::Expr, subquery::SubqueryExpr};
+
+/// [NOT] IN (SELECT ...) Expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InSubqueryExpr<'a> {
+    pub value: Box<Expr<'a>>,
+    pub subquery: SubqueryExpr<'a>,
+    pub negated: bool,
+    pub span: Span,
+}
+
+impl Named for InSubqueryExpr<'_> {
+    const NAMED: &'static str = "in subquery expression";
+}
+
+impl Spanned for InSubqueryExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> InSubqueryExpr<'a> {
+    /// Creates a new in subquery expr, `negated` for NOT IN
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = InSubqueryExpr::new(Expr::NAME(NameExpr::new("a")), subquery, false);
+    /// ```
+    pub fn new(value: Expr<'a>, subquery: SubqueryExpr<'a>, negated: bool) -> Self {
+        InSubqueryExpr {
+            value: Box::new(value),
+            subquery,
+            negated,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for InSubqueryExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let keyword = match self.negated {
+            true => "NOT IN",
+            false => "IN",
+        };
+        format!(
+            "{} {} {}",
+            self.value.to_sql_wrapped(options, 7),
+            options.keyword(keyword),
+            self.subquery.to_sql(options)
+        )
+    }
+}
+
+impl fmt::Display for InSubqueryExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use super::InSubqueryExpr;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(InSubqueryExpr::NAMED, "in subquery expression");
+    }
+}
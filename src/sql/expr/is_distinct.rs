@@ -0,0 +1,103 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    format::{fmt_sql, FormatOptions, ToSql},
+    span::{Span, Spanned},
+};
+
+use super::expr::Expr;
+
+/// IS [NOT] DISTINCT FROM Expression
+///
+/// A comparison which treats `NULL` as a value, `NULL IS DISTINCT FROM NULL` is false.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IsDistinctExpr<'a> {
+    pub left: Box<Expr<'a>>,
+    pub right: Box<Expr<'a>>,
+    pub negated: bool,
+    pub span: Span,
+}
+
+impl Named for IsDistinctExpr<'_> {
+    const NAMED: &'static str = "is distinct from expression";
+}
+
+impl Spanned for IsDistinctExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> IsDistinctExpr<'a> {
+    /// Creates a new is distinct from expr, `negated` for IS NOT DISTINCT FROM
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = IsDistinctExpr::new(
+    ///     Expr::NAME(NameExpr::new("a")),
+    ///     Expr::NAME(NameExpr::new("b")),
+    ///     false,
+    /// );
+    /// ```
+    pub fn new(left: Expr<'a>, right: Expr<'a>, negated: bool) -> Self {
+        IsDistinctExpr {
+            left: Box::new(left),
+            right: Box::new(right),
+            negated,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for IsDistinctExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let keyword = match self.negated {
+            true => "IS NOT DISTINCT FROM",
+            false => "IS DISTINCT FROM",
+        };
+        format!(
+            "{} {} {}",
+            self.left.to_sql_wrapped(options, 5),
+            options.keyword(keyword),
+            self.right.to_sql_wrapped(options, 5)
+        )
+    }
+}
+
+impl fmt::Display for IsDistinctExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::expr::{expr::Expr, name::NameExpr};
+
+    use super::IsDistinctExpr;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(IsDistinctExpr::NAMED, "is distinct from expression");
+    }
+
+    #[test]
+    fn it_new() {
+        let expr = IsDistinctExpr::new(
+            Expr::NAME(NameExpr::new("0d9e")),
+            Expr::NAME(NameExpr::new("5a71")),
+            true,
+        );
+        assert_eq!(*expr.left, Expr::NAME(NameExpr::new("0d9e")));
+        assert_eq!(*expr.right, Expr::NAME(NameExpr::new("5a71")));
+        assert!(expr.negated);
+    }
+}
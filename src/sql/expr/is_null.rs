@@ -0,0 +1,89 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    format::{fmt_sql, FormatOptions, ToSql},
+    span::{Span, Spanned},
+};
+
+use super::expr::Expr;
+
+/// IS [NOT] NULL Expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IsNullExpr<'a> {
+    pub value: Box<Expr<'a>>,
+    pub negated: bool,
+    pub span: Span,
+}
+
+impl Named for IsNullExpr<'_> {
+    const NAMED: &'static str = "is null expression";
+}
+
+impl Spanned for IsNullExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> IsNullExpr<'a> {
+    /// Creates a new is null expr, `negated` for IS NOT NULL
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = IsNullExpr::new(Expr::NAME(NameExpr::new("a")), false);
+    /// ```
+    pub fn new(value: Expr<'a>, negated: bool) -> Self {
+        IsNullExpr {
+            value: Box::new(value),
+            negated,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for IsNullExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let keyword = match self.negated {
+            true => "IS NOT NULL",
+            false => "IS NULL",
+        };
+        format!(
+            "{} {}",
+            self.value.to_sql_wrapped(options, 5),
+            options.keyword(keyword)
+        )
+    }
+}
+
+impl fmt::Display for IsNullExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::expr::{expr::Expr, name::NameExpr};
+
+    use super::IsNullExpr;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(IsNullExpr::NAMED, "is null expression");
+    }
+
+    #[test]
+    fn it_new() {
+        let expr = IsNullExpr::new(Expr::NAME(NameExpr::new("77b2")), true);
+        assert_eq!(*expr.value, Expr::NAME(NameExpr::new("77b2")));
+        assert!(expr.negated);
+    }
+}
@@ -1,17 +1,23 @@
+use std::fmt;
+
 use rsdb::Named;
 
 use crate::sql::{
     err::SyntaxError,
-    lexer::{lexer::Lexer, mat::LexerMatch},
-    parser::{LexerParser, SyntaxPattern},
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, LexerStart, SyntaxPattern},
+    span::{Span, Spanned},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItemsExpr<T>
 where
     T: Sized,
 {
     pub items: Vec<T>,
+    pub span: Span,
 }
 
 impl<T> Named for ItemsExpr<T>
@@ -21,51 +27,24 @@ where
     const NAMED: &'static str = "items";
 }
 
-impl<T> LexerParser for ItemsExpr<T>
+impl<'a, T> LexerParser<'a> for ItemsExpr<T>
 where
-    T: Sized + LexerParser,
+    T: Sized + LexerParser<'a> + LexerStart,
 {
-    fn parse(source: &SyntaxPattern, index: usize) -> Result<(Self, usize), SyntaxError>
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>>
     where
         Self: Sized,
     {
-        // get item
-        match source.items.get(index) {
-            // has item & parse item
-            Some(_) => match T::parse(source, index) {
-                // parse item ok & get lexer
-                Ok((item, item_end_index)) => match source.items.get(item_end_index) {
-                    // has lexer & check lexer
-                    Some(lexer) => match lexer {
-                        // lexer is comma & parse next items
-                        Lexer::COMMA(_) => match Self::parse(source, item_end_index + 1) {
-                            // parse next items ok & check next items's length
-                            Ok((next_items, next_end_index)) => {
-                                match next_items.min_len_check(source, item_end_index + 1, 1) {
-                                    // next items is empty
-                                    Some(err) => Err(err),
-                                    // next items is not empty
-                                    None => {
-                                        let mut mut_items = next_items;
-                                        mut_items.items.insert(0, item);
-                                        Ok((mut_items, next_end_index))
-                                    }
-                                }
-                            }
-                            Err(err) => Err(err),
-                        },
-                        // lexer is not comma
-                        _ => Ok((Self::new(vec![item]), item_end_index)),
-                    },
-                    // lexers is end, no comma
-                    None => Ok((Self::new(vec![item]), item_end_index)),
-                },
-                // parse item err
-                Err(err) => Err(err),
-            },
-            // lexers is end, no item
-            None => Ok((Self::new(vec![]), index)),
-        }
+        Self::parse_with(source, index, T::START, T::parse)
+    }
+}
+
+impl<T> Spanned for ItemsExpr<T>
+where
+    T: Sized,
+{
+    fn span(&self) -> Span {
+        self.span
     }
 }
 
@@ -74,37 +53,108 @@ where
     T: Sized,
 {
     pub fn new(items: Vec<T>) -> Self {
-        ItemsExpr { items }
+        ItemsExpr {
+            items,
+            span: Span::default(),
+        }
     }
 
-    pub fn min_len_check(
+    /// Parses comma separated items by `parse_item`, for items without an own `LexerParser`,
+    /// `start` are the lexers an item may start with
+    pub fn parse_with<'a>(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+        start: &[&'static str],
+        parse_item: impl Fn(&SyntaxPattern<'a>, usize) -> Result<(T, usize), SyntaxError<'a>>,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        // lexers is end, no item
+        if source.items.get(index).is_none() {
+            source.expect(index, start);
+            return Ok((
+                ItemsExpr {
+                    items: vec![],
+                    span: source.span(index, index),
+                },
+                index,
+            ));
+        }
+
+        let mut items = vec![];
+        let mut end_index = index;
+        loop {
+            // parse item
+            let (item, item_end_index) = parse_item(source, end_index)?;
+            items.push(item);
+            end_index = item_end_index;
+
+            match source.items.get(end_index) {
+                // lexer is comma & check next item
+                Some(Lexer::COMMA(_)) => {
+                    // lexers is end, no item after the comma
+                    if source.items.get(end_index + 1).is_none() {
+                        return Err(source.missing(end_index + 1, Self::NAMED, start));
+                    }
+                    end_index += 1;
+                }
+                // lexers is end or lexer is not comma
+                _ => {
+                    source.expect(end_index, &["','"]);
+                    return Ok((
+                        ItemsExpr {
+                            items,
+                            span: source.span(index, end_index),
+                        },
+                        end_index,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Returns a missing error if there are less than `min` items, the lexers an item
+    /// may start with are recorded by the parse at the end of the input
+    pub fn min_len_check<'a>(
         &self,
-        source: &SyntaxPattern,
+        source: &SyntaxPattern<'a>,
         index: usize,
         min: usize,
-    ) -> Option<SyntaxError> {
+    ) -> Option<SyntaxError<'a>> {
         if self.items.len() < min {
-            match source.items.get(index) {
-                Some(lexer) => Some(SyntaxError::new_missing(lexer.value(), Self::NAMED)),
-                None => Some(SyntaxError::new_missing(
-                    LexerMatch::new_eof(&source.text),
-                    Self::NAMED,
-                )),
-            }
+            Some(source.missing(index, Self::NAMED, &[]))
         } else {
             None
         }
     }
 }
 
+impl<T> ToSql for ItemsExpr<T>
+where
+    T: Sized + ToSql,
+{
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let items: Vec<String> = self.items.iter().map(|item| item.to_sql(options)).collect();
+        items.join(", ")
+    }
+}
+
+impl<T> fmt::Display for ItemsExpr<T>
+where
+    T: Sized + ToSql,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rsdb::Named;
 
     use crate::sql::{
         expr::name::NameExpr,
-        lexer::{lexer::Lexer, mat::LexerMatch},
+        lexer::{lexer::Lexer, mat::LexerMatch, pattern::LexerPattern},
         parser::{LexerParser, SyntaxPattern},
+        span::eq_ignore_span,
     };
 
     use super::ItemsExpr;
@@ -127,9 +177,9 @@ mod tests {
         let source = SyntaxPattern::new("", vec![]);
         let expr = ItemsExpr::new(vec![NameExpr::new("")]);
         // len greater than min
-        assert!(matches!(expr.min_len_check(&source, 0, 0), None));
+        assert!(expr.min_len_check(&source, 0, 0).is_none());
         // len equal min
-        assert!(matches!(expr.min_len_check(&source, 0, 1), None));
+        assert!(expr.min_len_check(&source, 0, 1).is_none());
         // len less than min
         assert!(matches!(
             expr.min_len_check(&source, 0, 2),
@@ -143,7 +193,7 @@ mod tests {
         let mut source = SyntaxPattern::new("", vec![]);
         assert!(matches!(
             ItemsExpr::<NameExpr>::parse(&source, 0),
-            Ok((expr, index)) if expr.items == vec![] && index == 0
+            Ok((expr, index)) if eq_ignore_span(&expr.items, &vec![]) && index == 0
         ));
         // one items
         source = SyntaxPattern::new(
@@ -152,7 +202,7 @@ mod tests {
         );
         assert!(matches!(
             ItemsExpr::<NameExpr>::parse(&source, 0),
-            Ok((expr, index)) if expr.items == vec![NameExpr::new("cee1")] && index == 1
+            Ok((expr, index)) if eq_ignore_span(&expr.items, &vec![NameExpr::new("cee1")]) && index == 1
         ));
         // two items
         source = SyntaxPattern::new(
@@ -165,7 +215,7 @@ mod tests {
         );
         assert!(matches!(
             ItemsExpr::<NameExpr>::parse(&source, 0),
-            Ok((expr, index)) if expr.items == vec![NameExpr::new("57f3"), NameExpr::new("fe80")] && index == 3
+            Ok((expr, index)) if eq_ignore_span(&expr.items, &vec![NameExpr::new("57f3"), NameExpr::new("fe80")]) && index == 3
         ));
         // no item after the comma
         source = SyntaxPattern::new(
@@ -178,6 +228,14 @@ mod tests {
         assert!(matches!(
             ItemsExpr::<NameExpr>::parse(&source, 0),
             Err(err) if err.cause == "missing items"
+                && source.expected_error(err.clone()).expected == ["identifier"]
+        ));
+        // many items
+        let text = "a, ".repeat(50_000) + "a";
+        source = SyntaxPattern::new(&text, LexerPattern::new(&text).matches().unwrap());
+        assert!(matches!(
+            ItemsExpr::<NameExpr>::parse(&source, 0),
+            Ok((expr, index)) if expr.items.len() == 50_001 && index == 100_001
         ));
     }
 }
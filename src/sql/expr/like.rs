@@ -0,0 +1,128 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    format::{fmt_sql, FormatOptions, ToSql},
+    span::{Span, Spanned},
+};
+
+use super::expr::Expr;
+
+/// Pattern matching operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LikeOp {
+    LIKE,
+    ILIKE,
+    SIMILARTO,
+}
+
+impl NamedEnum for LikeOp {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::LIKE => "LIKE",
+            Self::ILIKE => "ILIKE",
+            Self::SIMILARTO => "SIMILAR TO",
+        }
+    }
+}
+
+/// [NOT] LIKE Expression
+///
+/// `value [NOT] {LIKE | ILIKE | SIMILAR TO} pattern`, `ILIKE` ignores the case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LikeExpr<'a> {
+    pub value: Box<Expr<'a>>,
+    pub op: LikeOp,
+    pub pattern: Box<Expr<'a>>,
+    pub negated: bool,
+    pub span: Span,
+}
+
+impl Named for LikeExpr<'_> {
+    const NAMED: &'static str = "like expression";
+}
+
+impl Spanned for LikeExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LikeExpr<'a> {
+    /// Creates a new like expr, `negated` for NOT LIKE
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = LikeExpr::new(
+    ///     Expr::NAME(NameExpr::new("a")),
+    ///     LikeOp::LIKE,
+    ///     Expr::LITERAL(LiteralExpr::STRING("b%".into(), Span::default())),
+    ///     false,
+    /// );
+    /// ```
+    pub fn new(value: Expr<'a>, op: LikeOp, pattern: Expr<'a>, negated: bool) -> Self {
+        LikeExpr {
+            value: Box::new(value),
+            op,
+            pattern: Box::new(pattern),
+            negated,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for LikeExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let keyword = match self.negated {
+            true => format!("NOT {}", self.op.name()),
+            false => self.op.name().to_owned(),
+        };
+        format!(
+            "{} {} {}",
+            self.value.to_sql_wrapped(options, 7),
+            options.keyword(&keyword),
+            self.pattern.to_sql_wrapped(options, 7)
+        )
+    }
+}
+
+impl fmt::Display for LikeExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::expr::{expr::Expr, name::NameExpr};
+
+    use super::{LikeExpr, LikeOp};
+
+    #[test]
+    fn it_name() {
+        assert_eq!(LikeExpr::NAMED, "like expression");
+        assert_eq!(LikeOp::SIMILARTO.name(), "SIMILAR TO");
+    }
+
+    #[test]
+    fn it_new() {
+        let expr = LikeExpr::new(
+            Expr::NAME(NameExpr::new("3f0a")),
+            LikeOp::ILIKE,
+            Expr::NAME(NameExpr::new("b1c4")),
+            true,
+        );
+        assert_eq!(*expr.value, Expr::NAME(NameExpr::new("3f0a")));
+        assert_eq!(*expr.pattern, Expr::NAME(NameExpr::new("b1c4")));
+        assert!(expr.negated);
+        assert_eq!(expr.to_string(), "3f0a NOT ILIKE b1c4");
+    }
+}
@@ -0,0 +1,419 @@
+use std::{borrow::Cow, fmt, ops::Range};
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    err::SyntaxError,
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+/// Literal Expression
+///
+/// Numbers keep their source text, strings hold the unescaped value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum LiteralExpr<'a> {
+    INTEGER(Cow<'a, str>, Span),
+    DECIMAL(Cow<'a, str>, Span),
+    SCIENTIFIC(Cow<'a, str>, Span),
+    HEX(Cow<'a, str>, Span),
+    STRING(Cow<'a, str>, Span),
+}
+
+impl Named for LiteralExpr<'_> {
+    const NAMED: &'static str = "literal expression";
+}
+
+impl NamedEnum for LiteralExpr<'_> {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::INTEGER(..) => "INTEGER",
+            Self::DECIMAL(..) => "DECIMAL",
+            Self::SCIENTIFIC(..) => "SCIENTIFIC",
+            Self::HEX(..) => "HEX",
+            Self::STRING(..) => "STRING",
+        }
+    }
+}
+
+impl<'a> LexerParser<'a> for LiteralExpr<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>>
+    where
+        Self: Sized,
+    {
+        let span = source.span(index, index + 1);
+        match source.items.get(index) {
+            Some(Lexer::INTEGER(value)) => {
+                Ok((Self::INTEGER(value.as_str().into(), span), index + 1))
+            }
+            Some(Lexer::DECIMAL(value)) => {
+                Ok((Self::DECIMAL(value.as_str().into(), span), index + 1))
+            }
+            Some(Lexer::SCIENTIFIC(value)) => {
+                Ok((Self::SCIENTIFIC(value.as_str().into(), span), index + 1))
+            }
+            Some(Lexer::HEX(value)) => Ok((Self::HEX(value.as_str().into(), span), index + 1)),
+            Some(Lexer::STRING(value)) => Ok((
+                Self::STRING(Self::unescape_string(value.as_str()), span),
+                index + 1,
+            )),
+            Some(Lexer::ESTRING(value)) => match Self::unescape_estring(value.as_str()) {
+                Ok(text) => Ok((Self::STRING(Cow::Owned(text), span), index + 1)),
+                Err((range, cause)) => Err(source.invalid(
+                    Span::new(span.start + range.start, span.start + range.end),
+                    cause,
+                )),
+            },
+            _ => Err(source.missing(index, Self::NAMED, &["literal"])),
+        }
+    }
+}
+
+impl Spanned for LiteralExpr<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::INTEGER(_, span)
+            | Self::DECIMAL(_, span)
+            | Self::SCIENTIFIC(_, span)
+            | Self::HEX(_, span)
+            | Self::STRING(_, span) => *span,
+        }
+    }
+}
+
+impl<'a> LiteralExpr<'a> {
+    /// Unescapes a quoted string, `''` stands for `'`, the text is only copied if it
+    /// has escapes
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// assert_eq!(LiteralExpr::unescape_string("'it''s'"), "it's");
+    /// ```
+    pub fn unescape_string(text: &'a str) -> Cow<'a, str> {
+        let inner = &text[1..text.len() - 1];
+        match inner.contains("''") {
+            true => Cow::Owned(inner.replace("''", "'")),
+            false => Cow::Borrowed(inner),
+        }
+    }
+
+    /// Unescapes an E-string, both `''` and C-style backslash escapes are allowed
+    ///
+    /// Besides `\b`, `\f`, `\n`, `\r` and `\t` there are the byte escapes `\xh[h]` and
+    /// `\o[o[o]]` and the unicode escapes `\uXXXX` and `\UXXXXXXXX`, any other escaped char
+    /// stands for itself. Adjacent byte escapes must form valid UTF-8. An invalid escape is
+    /// returned with its byte range in the text.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// assert_eq!(LiteralExpr::unescape_estring(r"E'a\tb'"), Ok("a\tb".to_owned()));
+    /// assert_eq!(LiteralExpr::unescape_estring(r"E'\xC3\xA4'"), Ok("ä".to_owned()));
+    /// ```
+    pub fn unescape_estring(text: &str) -> Result<String, (Range<usize>, &'static str)> {
+        let inner = &text[..text.len() - 1];
+        let mut value = String::new();
+        // escaped bytes are kept until the next char
+        let mut bytes = vec![];
+        let mut bytes_start = 0;
+        let mut i = 2;
+        while i < inner.len() {
+            let start = i;
+            let c = inner[i..].chars().next().unwrap();
+            i += c.len_utf8();
+            let c = match c {
+                '\\' => {
+                    // the lexer only matches a backslash followed by a char
+                    let escaped = inner[i..].chars().next().unwrap();
+                    i += escaped.len_utf8();
+                    match escaped {
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'x' | '0'..='7' => {
+                            let (digits_start, radix, max_len) = match escaped {
+                                'x' => (i, 16, 2),
+                                _ => (i - 1, 8, 3),
+                            };
+                            let len = Self::digits_len(&inner[digits_start..], radix, max_len);
+                            if len == 0 {
+                                return Err((start..i, "invalid hexadecimal escape"));
+                            }
+                            i = digits_start + len;
+                            match u32::from_str_radix(&inner[digits_start..i], radix).unwrap() {
+                                0 => return Err((start..i, "null character not permitted")),
+                                byte @ 1..=0xFF => {
+                                    if bytes.is_empty() {
+                                        bytes_start = start;
+                                    }
+                                    bytes.push(byte as u8);
+                                    continue;
+                                }
+                                _ => return Err((start..i, "octal escape out of range")),
+                            }
+                        }
+                        'u' | 'U' => {
+                            let max_len = if escaped == 'u' { 4 } else { 8 };
+                            let len = Self::digits_len(&inner[i..], 16, max_len);
+                            if len < max_len {
+                                return Err((start..i + len, "invalid unicode escape"));
+                            }
+                            i += len;
+                            match char::from_u32(
+                                u32::from_str_radix(&inner[start + 2..i], 16).unwrap(),
+                            ) {
+                                Some('\0') => {
+                                    return Err((start..i, "null character not permitted"))
+                                }
+                                Some(c) => c,
+                                None => return Err((start..i, "invalid unicode code point")),
+                            }
+                        }
+                        other => other,
+                    }
+                }
+                // '' -> '
+                '\'' => {
+                    i += 1;
+                    '\''
+                }
+                _ => c,
+            };
+            Self::push_bytes(&mut value, &mut bytes, bytes_start..start)?;
+            value.push(c);
+        }
+        Self::push_bytes(&mut value, &mut bytes, bytes_start..i)?;
+        Ok(value)
+    }
+
+    /// The number of leading digits in the radix, at most `max_len`
+    fn digits_len(text: &str, radix: u32, max_len: usize) -> usize {
+        text.chars()
+            .take(max_len)
+            .take_while(|c| c.is_digit(radix))
+            .count()
+    }
+
+    /// Moves the escaped bytes to the value, `range` is the one of their escapes
+    fn push_bytes(
+        value: &mut String,
+        bytes: &mut Vec<u8>,
+        range: Range<usize>,
+    ) -> Result<(), (Range<usize>, &'static str)> {
+        if !bytes.is_empty() {
+            match std::str::from_utf8(bytes) {
+                Ok(text) => value.push_str(text),
+                Err(_) => return Err((range, "invalid UTF-8 byte sequence")),
+            }
+            bytes.clear();
+        }
+        Ok(())
+    }
+}
+
+impl ToSql for LiteralExpr<'_> {
+    fn to_sql(&self, _options: &FormatOptions) -> String {
+        match self {
+            Self::INTEGER(value, _)
+            | Self::DECIMAL(value, _)
+            | Self::SCIENTIFIC(value, _)
+            | Self::HEX(value, _) => value.to_string(),
+            Self::STRING(value, _) => format!("'{}'", value.replace('\'', "''")),
+        }
+    }
+}
+
+impl fmt::Display for LiteralExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::{
+        lexer::{lexer::Lexer, mat::LexerMatch},
+        parser::{LexerParser, SyntaxPattern},
+        span::{eq_ignore_span, Span},
+    };
+
+    use super::LiteralExpr;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(LiteralExpr::NAMED, "literal expression");
+        assert_eq!(
+            LiteralExpr::INTEGER("".into(), Span::default()).name(),
+            "INTEGER"
+        );
+        assert_eq!(
+            LiteralExpr::DECIMAL("".into(), Span::default()).name(),
+            "DECIMAL"
+        );
+        assert_eq!(
+            LiteralExpr::SCIENTIFIC("".into(), Span::default()).name(),
+            "SCIENTIFIC"
+        );
+        assert_eq!(LiteralExpr::HEX("".into(), Span::default()).name(), "HEX");
+        assert_eq!(
+            LiteralExpr::STRING("".into(), Span::default()).name(),
+            "STRING"
+        );
+    }
+
+    #[test]
+    fn it_unescape() {
+        assert_eq!(LiteralExpr::unescape_string("''"), "");
+        assert_eq!(LiteralExpr::unescape_string("'a''b'"), "a'b");
+        assert!(matches!(
+            LiteralExpr::unescape_string("'ab'"),
+            Cow::Borrowed("ab")
+        ));
+        let unescape = LiteralExpr::unescape_estring;
+        assert_eq!(unescape("E''"), Ok("".to_owned()));
+        assert_eq!(unescape(r"e'a\nb'"), Ok("a\nb".to_owned()));
+        assert_eq!(unescape(r"E'\'x'''"), Ok("'x'".to_owned()));
+        assert_eq!(unescape(r"E'\\'"), Ok("\\".to_owned()));
+        assert_eq!(unescape(r"E'\q\ä'"), Ok("qä".to_owned()));
+        // byte escapes
+        assert_eq!(
+            unescape(r"E'\x41\x4g\101\61\7'"),
+            Ok("A\u{4}gA1\u{7}".to_owned())
+        );
+        assert_eq!(unescape(r"E'\xC3\xA4\303\244'"), Ok("ää".to_owned()));
+        assert_eq!(unescape(r"E'\1234'"), Ok("S4".to_owned()));
+        // unicode escapes
+        assert_eq!(unescape(r"E'ä\U0001F600'"), Ok("ä😀".to_owned()));
+        assert_eq!(unescape(r"E'\u00e4\x41'"), Ok("äA".to_owned()));
+        // invalid escapes
+        assert_eq!(
+            unescape(r"E'a\xg'"),
+            Err((3..5, "invalid hexadecimal escape"))
+        );
+        assert_eq!(
+            unescape(r"E'\x00'"),
+            Err((2..6, "null character not permitted"))
+        );
+        assert_eq!(
+            unescape(r"E'\0'"),
+            Err((2..4, "null character not permitted"))
+        );
+        assert_eq!(
+            unescape(r"E'\400'"),
+            Err((2..6, "octal escape out of range"))
+        );
+        assert_eq!(unescape(r"E'\u12'"), Err((2..6, "invalid unicode escape")));
+        assert_eq!(
+            unescape(r"E'\U0000004'"),
+            Err((2..11, "invalid unicode escape"))
+        );
+        assert_eq!(
+            unescape(r"E'\uD800'"),
+            Err((2..8, "invalid unicode code point"))
+        );
+        assert_eq!(
+            unescape(r"E'\U00110000'"),
+            Err((2..12, "invalid unicode code point"))
+        );
+        assert_eq!(
+            unescape(r"E'\u0000'"),
+            Err((2..8, "null character not permitted"))
+        );
+        assert_eq!(
+            unescape(r"E'\xC3a'"),
+            Err((2..6, "invalid UTF-8 byte sequence"))
+        );
+        assert_eq!(
+            unescape(r"E'a\xC3\xC3'"),
+            Err((3..11, "invalid UTF-8 byte sequence"))
+        );
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            LiteralExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing literal expression"
+        ));
+        // current lexer is not a literal expression
+        source = SyntaxPattern::new(
+            "3e7a",
+            vec![Lexer::NAME(LexerMatch::new_full_match("3e7a"))],
+        );
+        assert!(matches!(
+            LiteralExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing literal expression"
+        ));
+        // numbers
+        source = SyntaxPattern::new("42", vec![Lexer::INTEGER(LexerMatch::new_full_match("42"))]);
+        assert!(matches!(
+            LiteralExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &LiteralExpr::INTEGER("42".into(), Span::default())) && index == 1
+                && matches!(expr, LiteralExpr::INTEGER(Cow::Borrowed(_), _))
+        ));
+        source = SyntaxPattern::new(
+            "4.2",
+            vec![Lexer::DECIMAL(LexerMatch::new_full_match("4.2"))],
+        );
+        assert!(matches!(
+            LiteralExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &LiteralExpr::DECIMAL("4.2".into(), Span::default())) && index == 1
+        ));
+        source = SyntaxPattern::new(
+            "4.2e-1",
+            vec![Lexer::SCIENTIFIC(LexerMatch::new_full_match("4.2e-1"))],
+        );
+        assert!(matches!(
+            LiteralExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &LiteralExpr::SCIENTIFIC("4.2e-1".into(), Span::default())) && index == 1
+        ));
+        source = SyntaxPattern::new("0x2A", vec![Lexer::HEX(LexerMatch::new_full_match("0x2A"))]);
+        assert!(matches!(
+            LiteralExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &LiteralExpr::HEX("0x2A".into(), Span::default())) && index == 1
+        ));
+        // strings
+        source = SyntaxPattern::new(
+            "'a''b'",
+            vec![Lexer::STRING(LexerMatch::new_full_match("'a''b'"))],
+        );
+        assert!(matches!(
+            LiteralExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &LiteralExpr::STRING("a'b".into(), Span::default())) && index == 1
+        ));
+        source = SyntaxPattern::new(
+            r"E'a\tb'",
+            vec![Lexer::ESTRING(LexerMatch::new_full_match(r"E'a\tb'"))],
+        );
+        assert!(matches!(
+            LiteralExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &LiteralExpr::STRING("a\tb".into(), Span::default())) && index == 1
+        ));
+        // invalid escape
+        source = SyntaxPattern::new(
+            r"E'a\u12'",
+            vec![Lexer::ESTRING(LexerMatch::new_full_match(r"E'a\u12'"))],
+        );
+        assert!(matches!(
+            LiteralExpr::parse(&source, 0),
+            Err(err) if err.cause == "invalid unicode escape" && err.start == 3 && err.end == 7
+        ));
+    }
+}
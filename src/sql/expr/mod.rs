@@ -1,3 +1,20 @@
+pub mod aggregate;
 pub mod alias;
+pub mod between;
+pub mod binary;
+pub mod exists;
+#[allow(clippy::module_inception)]
+pub mod expr;
+pub mod ident;
+pub mod in_list;
+pub mod in_subquery;
+pub mod is_distinct;
+pub mod is_null;
 pub mod items;
+pub mod like;
+pub mod literal;
 pub mod name;
+pub mod param;
+pub mod quantified;
+pub mod subquery;
+pub mod unary;
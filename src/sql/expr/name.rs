@@ -1,44 +1,139 @@
+use std::fmt;
+
 use rsdb::Named;
 
 use crate::sql::{
     err::SyntaxError,
-    lexer::{lexer::Lexer, mat::LexerMatch},
-    parser::{LexerParser, SyntaxPattern},
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, LexerStart, SyntaxPattern},
+    span::{Span, Spanned},
 };
 
+use super::ident::IdentExpr;
+
+/// Name Expression
+///
+/// A dot separated path of identifiers, e.g. `col`, `t1.col` or `db.schema.table`.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct NameExpr {
-    pub value: String,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NameExpr<'a> {
+    pub parts: Vec<IdentExpr<'a>>,
+    pub span: Span,
 }
 
-impl Named for NameExpr {
+impl Named for NameExpr<'_> {
     const NAMED: &'static str = "name expression";
 }
 
-impl LexerParser for NameExpr {
-    fn parse(source: &SyntaxPattern, index: usize) -> Result<(Self, usize), SyntaxError>
+impl LexerStart for NameExpr<'_> {
+    const START: &'static [&'static str] = &["identifier"];
+}
+
+impl<'a> LexerParser<'a> for NameExpr<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>>
     where
         Self: Sized,
     {
         match source.items.get(index) {
-            Some(lexer) => match lexer {
-                Lexer::NAME(value) => Ok((NameExpr::new(value.as_str()), index + 1)),
-                _ => Err(SyntaxError::new_missing(lexer.value(), Self::NAMED)),
-            },
-            None => Err(SyntaxError::new_missing(
-                LexerMatch::new_eof(&source.text),
-                Self::NAMED,
-            )),
+            Some(lexer) if lexer.is_ident() => {
+                let (part, mut end_index) = IdentExpr::parse(source, index)?;
+                let mut parts = vec![part];
+                // stops before a dot not followed by an identifier, e.g. `t1.*`
+                while let (Some(Lexer::DOT(_)), Some(true)) = (
+                    source.items.get(end_index),
+                    source.items.get(end_index + 1).map(Lexer::is_ident),
+                ) {
+                    let (part, part_end_index) = IdentExpr::parse(source, end_index + 1)?;
+                    parts.push(part);
+                    end_index = part_end_index;
+                }
+                Ok((
+                    NameExpr {
+                        parts,
+                        span: source.span(index, end_index),
+                    },
+                    end_index,
+                ))
+            }
+            _ => Err(source.missing(index, Self::NAMED, &["identifier"])),
         }
     }
 }
 
-impl NameExpr {
-    pub fn new(value: &str) -> Self {
+impl Spanned for NameExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> NameExpr<'a> {
+    /// Creates a new name of a single unquoted identifier
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = NameExpr::new("table_1");
+    /// ```
+    pub fn new(value: &'a str) -> Self {
+        NameExpr::new_parts(vec![IdentExpr::new(value)])
+    }
+
+    /// Creates a new name of a single quoted identifier
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = NameExpr::new_quoted("Order Details");
+    /// ```
+    pub fn new_quoted(value: &'a str) -> Self {
+        NameExpr::new_parts(vec![IdentExpr::new_quoted(value)])
+    }
+
+    /// Creates a new qualified name
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = NameExpr::new_parts(vec![IdentExpr::new("t1"), IdentExpr::new("id")]);
+    /// ```
+    pub fn new_parts(parts: Vec<IdentExpr<'a>>) -> Self {
         NameExpr {
-            value: value.to_owned(),
+            parts,
+            span: Span::default(),
         }
     }
+
+    /// The last identifier, e.g. the column of `t1.id`, `None` for a name without parts
+    pub fn last(&self) -> Option<&IdentExpr<'a>> {
+        self.parts.last()
+    }
+
+    /// The qualifier without the last identifier, e.g. the table of `t1.id`
+    pub fn qualifier(&self) -> &[IdentExpr<'a>] {
+        self.parts
+            .split_last()
+            .map_or(&[], |(_, qualifier)| qualifier)
+    }
+}
+
+impl ToSql for NameExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let parts: Vec<String> = self.parts.iter().map(|part| part.to_sql(options)).collect();
+        parts.join(".")
+    }
+}
+
+impl fmt::Display for NameExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
 }
 
 #[cfg(test)]
@@ -46,8 +141,10 @@ mod tests {
     use rsdb::Named;
 
     use crate::sql::{
-        lexer::{lexer::Lexer, mat::LexerMatch},
+        expr::ident::IdentExpr,
+        lexer::{lexer::Lexer, mat::LexerMatch, pattern::LexerPattern},
         parser::{LexerParser, SyntaxPattern},
+        span::eq_ignore_span,
     };
 
     use super::NameExpr;
@@ -59,7 +156,18 @@ mod tests {
 
     #[test]
     fn it_new() {
-        assert_eq!(NameExpr::new("4812").value, "4812");
+        assert_eq!(NameExpr::new("4812").parts, [IdentExpr::new("4812")]);
+        assert_eq!(
+            NameExpr::new_quoted("4B12").parts,
+            [IdentExpr::new_quoted("4B12")]
+        );
+        let expr = NameExpr::new_parts(vec![IdentExpr::new("a5"), IdentExpr::new("b6")]);
+        assert_eq!(expr.last(), Some(&IdentExpr::new("b6")));
+        assert_eq!(expr.qualifier(), [IdentExpr::new("a5")]);
+        // a name without parts
+        let expr = NameExpr::new_parts(vec![]);
+        assert_eq!(expr.last(), None);
+        assert!(expr.qualifier().is_empty());
     }
 
     #[test]
@@ -76,11 +184,29 @@ mod tests {
             NameExpr::parse(&source, 0),
             Err(err) if err.cause == "missing name expression"
         ));
-        // current lexer is not a name expression
+        // single identifier
         source = SyntaxPattern::new("", vec![Lexer::NAME(LexerMatch::new("8d06", 0, 4))]);
         assert!(matches!(
             NameExpr::parse(&source, 0),
-            Ok((expr, index)) if expr.value == "8d06" && index == 1
+            Ok((expr, index)) if eq_ignore_span(&expr, &NameExpr::new("8d06")) && index == 1
+        ));
+        // qualified name
+        let text = r#"db."Schema".t1 x"#;
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            NameExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &NameExpr::new_parts(vec![
+                IdentExpr::new("db"),
+                IdentExpr::new_quoted("Schema"),
+                IdentExpr::new("t1"),
+            ])) && expr.span.range() == (0..14) && expr.parts[1].span.range() == (3..11) && index == 5
+        ));
+        // stops before a dot not followed by an identifier
+        let text = "t1.*";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            NameExpr::parse(&source, 0),
+            Ok((expr, index)) if eq_ignore_span(&expr, &NameExpr::new("t1")) && index == 1
         ));
     }
 }
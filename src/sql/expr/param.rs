@@ -0,0 +1,123 @@
+use std::{borrow::Cow, fmt};
+
+use rsdb::Named;
+
+use crate::sql::{
+    err::SyntaxError,
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+/// Bind Parameter Expression
+///
+/// A placeholder bound when the statement is executed: positional `?`, numbered `$1`
+/// or named `:name`. The source text is kept as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParamExpr<'a> {
+    pub value: Cow<'a, str>,
+    pub span: Span,
+}
+
+impl Named for ParamExpr<'_> {
+    const NAMED: &'static str = "parameter";
+}
+
+impl<'a> LexerParser<'a> for ParamExpr<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>>
+    where
+        Self: Sized,
+    {
+        match source.items.get(index) {
+            Some(Lexer::PARAM(value)) => Ok((
+                ParamExpr {
+                    span: source.span(index, index + 1),
+                    ..ParamExpr::new(value.as_str())
+                },
+                index + 1,
+            )),
+            _ => Err(source.missing(index, Self::NAMED, &["parameter"])),
+        }
+    }
+}
+
+impl Spanned for ParamExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> ParamExpr<'a> {
+    /// Creates a new parameter from its source text
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = ParamExpr::new("$1");
+    /// ```
+    pub fn new(value: &'a str) -> Self {
+        ParamExpr {
+            value: Cow::Borrowed(value),
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for ParamExpr<'_> {
+    fn to_sql(&self, _options: &FormatOptions) -> String {
+        self.value.to_string()
+    }
+}
+
+impl fmt::Display for ParamExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::{
+        lexer::pattern::LexerPattern,
+        parser::{LexerParser, SyntaxPattern},
+        span::eq_ignore_span,
+    };
+
+    use super::ParamExpr;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(ParamExpr::NAMED, "parameter");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            ParamExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing parameter"
+        ));
+        // current lexer is not a parameter
+        let text = "a";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            ParamExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing parameter"
+        ));
+        // positional, numbered and named parameters
+        for text in ["?", "$3", ":id"] {
+            source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+            assert!(matches!(
+                ParamExpr::parse(&source, 0),
+                Ok((expr, index)) if eq_ignore_span(&expr, &ParamExpr::new(text)) && expr.span.range() == (0..text.len()) && index == 1
+            ));
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    format::{fmt_sql, FormatOptions, ToSql},
+    span::{Span, Spanned},
+};
+
+use super::{binary::BinaryOp, expr::Expr, subquery::SubqueryExpr};
+
+/// Quantifier of a comparison with a subquery
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Quantifier {
+    ANY,
+    ALL,
+}
+
+impl NamedEnum for Quantifier {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::ANY => "ANY",
+            Self::ALL => "ALL",
+        }
+    }
+}
+
+/// Quantified Comparison Expression
+///
+/// `expr op ANY (SELECT ...)` or `expr op ALL (SELECT ...)`, `op` is a comparison operator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuantifiedExpr<'a> {
+    pub left: Box<Expr<'a>>,
+    pub op: BinaryOp,
+    pub quantifier: Quantifier,
+    pub subquery: SubqueryExpr<'a>,
+    pub span: Span,
+}
+
+impl Named for QuantifiedExpr<'_> {
+    const NAMED: &'static str = "quantified expression";
+}
+
+impl Spanned for QuantifiedExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> QuantifiedExpr<'a> {
+    /// Creates a new quantified comparison
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = QuantifiedExpr::new(
+    ///     Expr::NAME(NameExpr::new("a")),
+    ///     BinaryOp::GT,
+    ///     Quantifier::ALL,
+    ///     subquery,
+    /// );
+    /// ```
+    pub fn new(
+        left: Expr<'a>,
+        op: BinaryOp,
+        quantifier: Quantifier,
+        subquery: SubqueryExpr<'a>,
+    ) -> Self {
+        QuantifiedExpr {
+            left: Box::new(left),
+            op,
+            quantifier,
+            subquery,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for QuantifiedExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        format!(
+            "{} {} {} {}",
+            self.left.to_sql_wrapped(options, 6),
+            self.op.name(),
+            options.keyword(self.quantifier.name()),
+            self.subquery.to_sql(options)
+        )
+    }
+}
+
+impl fmt::Display for QuantifiedExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use super::{QuantifiedExpr, Quantifier};
+
+    #[test]
+    fn it_name() {
+        assert_eq!(QuantifiedExpr::NAMED, "quantified expression");
+        assert_eq!(Quantifier::ALL.name(), "ALL");
+    }
+}
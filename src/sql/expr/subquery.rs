@@ -0,0 +1,214 @@
+use std::fmt;
+
+use rsdb::Named;
+
+use crate::sql::{
+    err::SyntaxError,
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+    stmt::query::QueryStmt,
+};
+
+/// Subquery Expression
+///
+/// `(SELECT ...)` or `(query)`, a scalar subquery in an expression, a derived table in `FROM`
+/// or the right side of `IN`, `EXISTS`, `ANY` and `ALL`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SubqueryExpr<'a> {
+    pub stmt: Box<QueryStmt<'a>>,
+    pub span: Span,
+}
+
+impl Named for SubqueryExpr<'_> {
+    const NAMED: &'static str = "subquery";
+}
+
+impl Spanned for SubqueryExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for SubqueryExpr<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        match (source.items.get(index), source.items.get(index + 1)) {
+            (
+                Some(Lexer::LPAREN(_)),
+                Some(Lexer::SELECT(_) | Lexer::WITH(_) | Lexer::LPAREN(_)),
+            ) => {
+                let (stmt, end_index) = QueryStmt::parse(source, index + 1)?;
+                match source.items.get(end_index) {
+                    Some(Lexer::RPAREN(_)) => Ok((
+                        SubqueryExpr {
+                            stmt: Box::new(stmt),
+                            span: source.span(index, end_index + 1),
+                        },
+                        end_index + 1,
+                    )),
+                    _ => Err(source.missing(end_index, ")", &["')'"])),
+                }
+            }
+            (Some(Lexer::LPAREN(_)), _) => Err(source.missing(index + 1, "SELECT", &["SELECT"])),
+            _ => Err(source.missing(index, Self::NAMED, &["'('"])),
+        }
+    }
+}
+
+impl<'a> SubqueryExpr<'a> {
+    /// Creates a new subquery of a query
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// if let Stmt::SELECT(stmt) = parse_stmt("SELECT a FROM t").unwrap() {
+    ///     let expr = SubqueryExpr::new(QueryStmt::SELECT(stmt));
+    /// }
+    /// ```
+    pub fn new(stmt: QueryStmt<'a>) -> Self {
+        SubqueryExpr {
+            stmt: Box::new(stmt),
+            span: Span::default(),
+        }
+    }
+
+    /// Whether the lexers at `index` start a subquery, i.e. `(SELECT` or `(WITH`
+    ///
+    /// In `((SELECT ...) ...` the inner parentheses are a query operand if a set operator,
+    /// a query clause or `)` follows them, otherwise an expression, e.g. `((SELECT ...) = 1)`.
+    pub fn is_start(source: &SyntaxPattern<'_>, index: usize) -> bool {
+        match (source.items.get(index), source.items.get(index + 1)) {
+            (Some(Lexer::LPAREN(_)), Some(Lexer::SELECT(_) | Lexer::WITH(_))) => true,
+            (Some(Lexer::LPAREN(_)), Some(Lexer::LPAREN(_))) => {
+                if !Self::is_start(source, index + 1) {
+                    return false;
+                }
+                // skip the inner parentheses
+                let mut depth = 0;
+                let mut end_index = index + 1;
+                loop {
+                    match source.items.get(end_index) {
+                        Some(Lexer::LPAREN(_)) => depth += 1,
+                        Some(Lexer::RPAREN(_)) if depth == 1 => break,
+                        Some(Lexer::RPAREN(_)) => depth -= 1,
+                        Some(_) => {}
+                        None => return true,
+                    }
+                    end_index += 1;
+                }
+                matches!(
+                    source.items.get(end_index + 1),
+                    Some(
+                        Lexer::UNION(_)
+                            | Lexer::INTERSECT(_)
+                            | Lexer::EXCEPT(_)
+                            | Lexer::ORDER(_)
+                            | Lexer::LIMIT(_)
+                            | Lexer::OFFSET(_)
+                            | Lexer::FETCH(_)
+                            | Lexer::RPAREN(_)
+                    )
+                )
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ToSql for SubqueryExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        format!("({})", self.stmt.to_sql(options))
+    }
+}
+
+impl fmt::Display for SubqueryExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::Named;
+
+    use crate::sql::{
+        lexer::pattern::LexerPattern,
+        parser::{parse_stmt, LexerParser, SyntaxPattern},
+    };
+
+    use super::SubqueryExpr;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(SubqueryExpr::NAMED, "subquery");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            SubqueryExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing subquery"
+        ));
+        // parentheses without a select statement
+        let text = "(a)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(!SubqueryExpr::is_start(&source, 0));
+        assert!(matches!(
+            SubqueryExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing SELECT"
+        ));
+        // select statement
+        let text = "(SELECT a FROM t WHERE b = 1) AS x";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(SubqueryExpr::is_start(&source, 0));
+        assert!(matches!(
+            SubqueryExpr::parse(&source, 0),
+            Ok((expr, index)) if expr.stmt.to_string() == "SELECT a FROM t WHERE b = 1"
+                && expr.span.range() == (0..29) && index == 10
+        ));
+        // set operation of parenthesized queries
+        let text = "((SELECT a FROM t) UNION (SELECT a FROM u))";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(SubqueryExpr::is_start(&source, 0));
+        assert!(matches!(
+            SubqueryExpr::parse(&source, 0),
+            Ok((expr, index)) if expr.stmt.to_string() == "SELECT a FROM t UNION SELECT a FROM u"
+                && index == 15
+        ));
+        // parenthesized query
+        let text = "((SELECT a FROM t))";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(SubqueryExpr::is_start(&source, 0));
+        // expression of a subquery
+        let text = "((SELECT a FROM t) = 1)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(!SubqueryExpr::is_start(&source, 0));
+        assert!(SubqueryExpr::is_start(&source, 1));
+        // missing )
+        let text = "(SELECT a FROM t";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SubqueryExpr::parse(&source, 0),
+            Err(err) if err.cause == "missing )"
+        ));
+    }
+
+    #[test]
+    fn it_parse_stmt() {
+        assert!(parse_stmt(
+            "SELECT a FROM t WHERE b > (SELECT MAX(b) FROM u WHERE u.c = t.c) GROUP BY a"
+        )
+        .is_ok());
+        // the subquery is closed
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t WHERE a IN (SELECT b FROM u"),
+            Err(err) if err.cause.ends_with("WHERE, ')', ',', got end of input") && err.start == 42
+        ));
+    }
+}
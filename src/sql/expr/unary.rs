@@ -0,0 +1,124 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    format::{fmt_sql, FormatOptions, ToSql},
+    span::{Span, Spanned},
+};
+
+use super::expr::Expr;
+
+/// Unary operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnaryOp {
+    NOT,
+    NEG,
+}
+
+impl UnaryOp {
+    /// Binding strength, see `Expr::precedence`
+    pub fn precedence(&self) -> usize {
+        match self {
+            Self::NOT => 3,
+            Self::NEG => 10,
+        }
+    }
+}
+
+impl NamedEnum for UnaryOp {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::NOT => "NOT",
+            Self::NEG => "-",
+        }
+    }
+}
+
+/// Unary Expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnaryExpr<'a> {
+    pub op: UnaryOp,
+    pub value: Box<Expr<'a>>,
+    pub span: Span,
+}
+
+impl Named for UnaryExpr<'_> {
+    const NAMED: &'static str = "unary expression";
+}
+
+impl Spanned for UnaryExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> UnaryExpr<'a> {
+    /// Creates a new unary expr
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = UnaryExpr::new(UnaryOp::NOT, Expr::NAME(NameExpr::new("a")));
+    /// ```
+    pub fn new(op: UnaryOp, value: Expr<'a>) -> Self {
+        UnaryExpr {
+            op,
+            value: Box::new(value),
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for UnaryExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let value = self.value.to_sql_wrapped(options, self.op.precedence());
+        match self.op {
+            UnaryOp::NOT => format!("{} {}", options.keyword(self.op.name()), value),
+            // `--` would start a comment
+            UnaryOp::NEG if value.starts_with('-') => format!("- {}", value),
+            UnaryOp::NEG => format!("-{}", value),
+        }
+    }
+}
+
+impl fmt::Display for UnaryExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::expr::{expr::Expr, name::NameExpr};
+
+    use super::{UnaryExpr, UnaryOp};
+
+    #[test]
+    fn it_name() {
+        assert_eq!(UnaryExpr::NAMED, "unary expression");
+        assert_eq!(UnaryOp::NOT.name(), "NOT");
+        assert_eq!(UnaryOp::NEG.name(), "-");
+    }
+
+    #[test]
+    fn it_new() {
+        let expr = UnaryExpr::new(UnaryOp::NOT, Expr::NAME(NameExpr::new("e1d0")));
+        assert_eq!(expr.op, UnaryOp::NOT);
+        assert_eq!(*expr.value, Expr::NAME(NameExpr::new("e1d0")));
+    }
+
+    #[test]
+    fn it_to_sql() {
+        let expr = UnaryExpr::new(UnaryOp::NEG, Expr::NAME(NameExpr::new("a")));
+        assert_eq!(expr.to_string(), "-a");
+        let expr = UnaryExpr::new(UnaryOp::NEG, Expr::UNARY(expr));
+        assert_eq!(expr.to_string(), "- -a");
+    }
+}
@@ -0,0 +1,261 @@
+use std::fmt;
+
+use super::expr::{
+    binary::{BinaryExpr, BinaryOp},
+    expr::Expr,
+};
+
+/// Case of the keywords written by `ToSql`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordCase {
+    UPPER,
+    LOWER,
+}
+
+/// Options of `ToSql`
+///
+/// A statement which fits into `line_width` is written on one line, otherwise
+/// every clause starts a new line and a clause which still does not fit puts
+/// each of its items on an own line, indented by `indent` spaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub keyword_case: KeywordCase,
+    pub indent: usize,
+    pub line_width: usize,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            keyword_case: KeywordCase::UPPER,
+            indent: 2,
+            line_width: 80,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Everything on one line with upper case keywords, used by `Display`
+    pub fn compact() -> Self {
+        FormatOptions {
+            line_width: usize::MAX,
+            ..Self::default()
+        }
+    }
+
+    /// Writes the keyword in the configured case
+    pub fn keyword(&self, keyword: &str) -> String {
+        match self.keyword_case {
+            KeywordCase::UPPER => keyword.to_uppercase(),
+            KeywordCase::LOWER => keyword.to_lowercase(),
+        }
+    }
+
+    /// Writes a clause, its items are put on own lines if it does not fit into a line
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```text
+    /// let options = FormatOptions { line_width: 10, ..FormatOptions::default() };
+    /// let items = vec!["a".to_owned(), "b".to_owned()];
+    /// assert_eq!(options.clause("SELECT", &items, ","), "SELECT\n  a,\n  b");
+    /// ```
+    pub fn clause(&self, keyword: &str, items: &[String], separator: &str) -> String {
+        self.clause_head(&self.keyword(keyword), items, separator)
+    }
+
+    /// Writes a clause like `clause`, the head is already written, e.g. `SELECT DISTINCT ON (a)`
+    pub fn clause_head(&self, head: &str, items: &[String], separator: &str) -> String {
+        let line = format!("{} {}", head, items.join(&format!("{} ", separator)));
+        if line.chars().count() <= self.line_width {
+            return line;
+        }
+        let indent = " ".repeat(self.indent);
+        let lines: Vec<String> = items
+            .iter()
+            .map(|item| format!("{}{}", indent, item))
+            .collect();
+        format!("{}\n{}", head, lines.join(&format!("{}\n", separator)))
+    }
+
+    /// Writes a condition clause like `WHERE`, a top level `AND` chain is split into items
+    pub fn condition(&self, keyword: &str, expr: &Expr) -> String {
+        // a = 1 AND b = 2 AND c = 3 -> [a = 1, AND b = 2, AND c = 3]
+        let mut items = vec![];
+        let mut expr = expr;
+        while let Expr::BINARY(BinaryExpr {
+            left,
+            op: BinaryOp::AND,
+            right,
+            ..
+        }) = expr
+        {
+            items.push(format!(
+                "{} {}",
+                self.keyword("AND"),
+                right.to_sql_wrapped(self, BinaryOp::AND.precedence() + 1)
+            ));
+            expr = left;
+        }
+        // only the first operand of an AND chain is wrapped, e.g. an OR
+        items.push(match items.is_empty() {
+            true => expr.to_sql(self),
+            false => expr.to_sql_wrapped(self, BinaryOp::AND.precedence()),
+        });
+        items.reverse();
+        self.clause(keyword, &items, "")
+    }
+
+    /// Writes the clauses of a statement, on one line if they fit
+    pub fn clauses(&self, clauses: &[String]) -> String {
+        let line = clauses.join(" ");
+        match line.chars().count() <= self.line_width {
+            true => line,
+            false => clauses.join("\n"),
+        }
+    }
+}
+
+/// Writes an AST node as SQL, parsing the SQL again gives an equal node
+pub trait ToSql {
+    fn to_sql(&self, options: &FormatOptions) -> String;
+}
+
+/// `Display` of a `ToSql` node, everything on one line
+pub fn fmt_sql<T: ToSql>(node: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&node.to_sql(&FormatOptions::compact()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::{parser::parse_stmt, span::eq_ignore_span, stmt::stmt::Stmt};
+
+    use super::{FormatOptions, KeywordCase, ToSql};
+
+    const SQLS: [&str; 30] = [
+        "SELECT * FROM t",
+        "select a AS \"Ab\"\"c\", t.*, 1, 'it''s', E'a\\nb', 0x1F, 1.5e-3 from db.s.t AS x, u",
+        "SELECT a FROM t WHERE NOT a = 1 OR b <> 2 AND (c < 3 OR d >= .5)",
+        "SELECT a FROM t WHERE (a = b) = c AND a = (b = c)",
+        "SELECT a FROM t WHERE a OR (b OR c) AND (d AND e)",
+        "SELECT a FROM t WHERE NOT (a IS NULL) AND (b IS NOT NULL) IS NULL",
+        "SELECT a FROM t WHERE a NOT BETWEEN (b OR c) AND 3 AND NOT a BETWEEN 1 AND 2",
+        "SELECT a FROM t WHERE a IN (1, b = 2, (3)) AND (a NOT IN (c)) NOT IN (d)",
+        "SELECT a AS x, b FROM t WHERE c = 1 ORDER BY x DESC NULLS LAST, 2, b ASC, last NULLS FIRST",
+        "select first, nulls from asc order by nulls desc, 1",
+        "SELECT a FROM t WHERE b = ? AND c IN ($1, :name) LIMIT 10 OFFSET 20",
+        "SELECT a FROM t ORDER BY a OFFSET :skip ROWS FETCH FIRST :take ROWS WITH TIES",
+        "SELECT a FROM t FETCH NEXT ROW ONLY",
+        "SELECT a, COUNT(*), count(DISTINCT b), Sum(c) AS s, avg(d), min(e), max(f) FROM t GROUP BY a",
+        "SELECT * FROM a JOIN b ON a.id = b.id LEFT OUTER JOIN c USING (id, k) CROSS JOIN d, e NATURAL FULL JOIN f",
+        "SELECT * FROM a AS x RIGHT JOIN (b INNER JOIN c ON b.k = c.k) AS y ON x.k = y.k, ((g CROSS JOIN h) CROSS JOIN i)",
+        "SELECT a, b FROM t WHERE c = 1 GROUP BY a, b HAVING COUNT(*) > 1 AND MIN(d) = 2 ORDER BY MAX(e) DESC",
+        "SELECT a, (SELECT MAX(b) FROM u WHERE u.k = t.k) AS m FROM (SELECT a, k FROM v) AS t JOIN (SELECT k FROM w) AS x USING (k)",
+        "SELECT a FROM t WHERE a IN (SELECT a FROM u) AND NOT EXISTS (SELECT * FROM v WHERE v.a = t.a) OR b NOT IN (SELECT b FROM w)",
+        "SELECT a FROM t WHERE a > ALL (SELECT a FROM u) AND b = ANY (SELECT b FROM v) AND (SELECT COUNT(*) FROM w) >= 2",
+        "WITH a AS (SELECT x FROM t), b (y, z) AS MATERIALIZED (SELECT y, z FROM a) SELECT * FROM b WHERE y IN (WITH c AS NOT MATERIALIZED (SELECT 1 FROM d) SELECT * FROM c)",
+        "WITH RECURSIVE tree (id, parent_id) AS (SELECT id, parent_id FROM org WHERE parent_id IS NULL) SELECT id FROM tree",
+        "SELECT a FROM t UNION ALL SELECT a FROM u INTERSECT SELECT a FROM v ORDER BY 1 LIMIT 5",
+        "(SELECT a FROM t LIMIT 1) EXCEPT (SELECT a FROM u UNION SELECT a FROM v) EXCEPT ALL SELECT a FROM w",
+        "SELECT DISTINCT ON (a, \"B\".c) a, d FROM t ORDER BY a, d DESC",
+        "select all a from t union select distinct a, count(distinct b) from u group by a",
+        "WITH RECURSIVE r (n) AS (SELECT 1 FROM one UNION ALL SELECT n FROM r JOIN s ON r.n = s.m) SELECT n FROM r WHERE n IN ((SELECT n FROM x) INTERSECT (SELECT n FROM y))",
+        "SELECT a * 2 + -b, c || 'x' AS d, * FROM t WHERE e NOT LIKE 'a%' AND f IS DISTINCT FROM g % 3 OR h ILIKE i AND j SIMILAR TO 'k' ORDER BY a / 2",
+        "SELECT (a + b) * c, a - (b - c), - -a, -(a * b), a || (b || c) FROM t WHERE (a = b) = c AND NOT a + 1 BETWEEN b * 2 AND c",
+        "SELECT a FROM t WHERE a IS NOT DISTINCT FROM (b IS NULL) AND (c LIKE d) NOT LIKE e AND f - 1 > ANY (SELECT g FROM u)",
+    ];
+
+    fn round_trip(stmt: &Stmt, options: &FormatOptions) {
+        let sql = stmt.to_sql(options);
+        assert!(eq_ignore_span(&parse_stmt(&sql).unwrap(), stmt), "{}", sql);
+    }
+
+    #[test]
+    fn it_keyword() {
+        let mut options = FormatOptions::default();
+        assert_eq!(options.keyword("Select"), "SELECT");
+        options.keyword_case = KeywordCase::LOWER;
+        assert_eq!(options.keyword("Select"), "select");
+    }
+
+    #[test]
+    fn it_round_trip() {
+        let narrow = FormatOptions {
+            keyword_case: KeywordCase::LOWER,
+            indent: 4,
+            line_width: 10,
+        };
+        for sql in SQLS {
+            let stmt = parse_stmt(sql).unwrap();
+            round_trip(&stmt, &FormatOptions::compact());
+            round_trip(&stmt, &FormatOptions::default());
+            round_trip(&stmt, &narrow);
+            assert!(eq_ignore_span(
+                &parse_stmt(&stmt.to_string()).unwrap(),
+                &stmt
+            ));
+        }
+    }
+
+    #[test]
+    fn it_to_sql() {
+        let stmt = parse_stmt("select A as x, 'b''c' from T where a=1 and not(b or c)").unwrap();
+        assert_eq!(
+            stmt.to_string(),
+            "SELECT a AS x, 'b''c' FROM t WHERE a = 1 AND NOT (b OR c)"
+        );
+        let options = FormatOptions {
+            keyword_case: KeywordCase::LOWER,
+            indent: 4,
+            line_width: 20,
+        };
+        assert_eq!(
+            stmt.to_sql(&options),
+            "select\n    a as x,\n    'b''c'\nfrom t\nwhere\n    a = 1\n    and not (b or c)"
+        );
+        // fits into a line
+        let options = FormatOptions {
+            line_width: 60,
+            ..FormatOptions::default()
+        };
+        assert_eq!(stmt.to_sql(&options), stmt.to_string());
+        // a top level OR is not wrapped
+        let stmt =
+            parse_stmt("SELECT a FROM t WHERE a = 1 OR b = 2 GROUP BY a HAVING a OR b").unwrap();
+        assert_eq!(
+            stmt.to_string(),
+            "SELECT a FROM t WHERE a = 1 OR b = 2 GROUP BY a HAVING a OR b"
+        );
+        let stmt = parse_stmt("SELECT a FROM t WHERE (a OR b) AND c").unwrap();
+        assert_eq!(stmt.to_string(), "SELECT a FROM t WHERE (a OR b) AND c");
+        // the line width counts characters
+        let stmt = parse_stmt("SELECT 'äöü' FROM t").unwrap();
+        let options = FormatOptions {
+            line_width: 20,
+            ..FormatOptions::default()
+        };
+        assert_eq!(stmt.to_sql(&options), "SELECT 'äöü' FROM t");
+        let options = FormatOptions {
+            line_width: 12,
+            ..FormatOptions::default()
+        };
+        assert_eq!(stmt.to_sql(&options), "SELECT 'äöü'\nFROM t");
+        // set operations keep only the parentheses they need
+        let stmt = parse_stmt(
+            "((select a from t) union select a from u) union (select a from v intersect select a from w)",
+        )
+        .unwrap();
+        assert_eq!(
+            stmt.to_string(),
+            "SELECT a FROM t UNION SELECT a FROM u UNION SELECT a FROM v INTERSECT SELECT a FROM w"
+        );
+        let stmt =
+            parse_stmt("select a from t except (select a from u except select a from v)").unwrap();
+        assert_eq!(
+            stmt.to_string(),
+            "SELECT a FROM t EXCEPT (SELECT a FROM u EXCEPT SELECT a FROM v)"
+        );
+    }
+}
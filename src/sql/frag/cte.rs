@@ -0,0 +1,235 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    err::SyntaxError,
+    expr::{ident::IdentExpr, items::ItemsExpr, subquery::SubqueryExpr},
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, LexerStart, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+/// Hint whether a common table expression is computed once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CteMaterialized {
+    MATERIALIZED,
+    NOTMATERIALIZED,
+}
+
+impl NamedEnum for CteMaterialized {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::MATERIALIZED => "MATERIALIZED",
+            Self::NOTMATERIALIZED => "NOT MATERIALIZED",
+        }
+    }
+}
+
+/// Common Table Expression
+///
+/// `name [(col, ...)] AS [[NOT] MATERIALIZED] (SELECT ...)`, an unset hint keeps
+/// the database default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cte<'a> {
+    pub name: IdentExpr<'a>,
+    pub columns: Option<ItemsExpr<IdentExpr<'a>>>,
+    pub materialized: Option<CteMaterialized>,
+    pub query: SubqueryExpr<'a>,
+    pub span: Span,
+}
+
+impl Named for Cte<'_> {
+    const NAMED: &'static str = "common table expression";
+}
+
+impl LexerStart for Cte<'_> {
+    const START: &'static [&'static str] = &["identifier"];
+}
+
+impl Spanned for Cte<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for Cte<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        let (name, name_end_index) = IdentExpr::parse(source, index)?;
+
+        let (columns, columns_end_index) = match source.items.get(name_end_index) {
+            // t (SELECT ...) misses AS, not a column
+            Some(Lexer::LPAREN(_)) if !SubqueryExpr::is_start(source, name_end_index) => {
+                let (columns, end_index) =
+                    ItemsExpr::<IdentExpr>::parse(source, name_end_index + 1)?;
+                if let Some(err) = columns.min_len_check(source, name_end_index + 1, 1) {
+                    return Err(err);
+                }
+                match source.items.get(end_index) {
+                    Some(Lexer::RPAREN(_)) => (Some(columns), end_index + 1),
+                    _ => return Err(source.missing(end_index, ")", &["')'"])),
+                }
+            }
+            _ => {
+                source.expect(name_end_index, &["'('"]);
+                (None, name_end_index)
+            }
+        };
+
+        let query_index = match source.items.get(columns_end_index) {
+            Some(Lexer::ALIAS(_)) => columns_end_index + 1,
+            _ => return Err(source.missing(columns_end_index, "AS", &["AS"])),
+        };
+        let (materialized, query_index) = match (
+            source.items.get(query_index),
+            source.items.get(query_index + 1),
+        ) {
+            (Some(Lexer::MATERIALIZED(_)), _) => {
+                (Some(CteMaterialized::MATERIALIZED), query_index + 1)
+            }
+            (Some(Lexer::NOT(_)), Some(Lexer::MATERIALIZED(_))) => {
+                (Some(CteMaterialized::NOTMATERIALIZED), query_index + 2)
+            }
+            (Some(Lexer::NOT(_)), _) => {
+                return Err(source.missing(query_index + 1, "MATERIALIZED", &["MATERIALIZED"]))
+            }
+            _ => {
+                source.expect(query_index, &["MATERIALIZED", "NOT"]);
+                (None, query_index)
+            }
+        };
+        let (query, end_index) = SubqueryExpr::parse(source, query_index)?;
+
+        Ok((
+            Cte {
+                name,
+                columns,
+                materialized,
+                query,
+                span: source.span(index, end_index),
+            },
+            end_index,
+        ))
+    }
+}
+
+impl<'a> Cte<'a> {
+    /// Creates a new common table expression without column list and hint
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let cte = Cte::new(IdentExpr::new("t"), SubqueryExpr::new(stmt));
+    /// ```
+    pub fn new(name: IdentExpr<'a>, query: SubqueryExpr<'a>) -> Self {
+        Cte {
+            name,
+            columns: None,
+            materialized: None,
+            query,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for Cte<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let mut sql = self.name.to_sql(options);
+        if let Some(columns) = &self.columns {
+            sql.push_str(&format!(" ({})", columns.to_sql(options)));
+        }
+        sql.push(' ');
+        sql.push_str(&options.keyword("AS"));
+        if let Some(materialized) = self.materialized {
+            sql.push(' ');
+            sql.push_str(&options.keyword(materialized.name()));
+        }
+        sql.push(' ');
+        sql.push_str(&self.query.to_sql(options));
+        sql
+    }
+}
+
+impl fmt::Display for Cte<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::{
+        expr::ident::IdentExpr,
+        lexer::pattern::LexerPattern,
+        parser::{LexerParser, SyntaxPattern},
+        span::eq_ignore_span,
+    };
+
+    use super::{Cte, CteMaterialized};
+
+    #[test]
+    fn it_name() {
+        assert_eq!(Cte::NAMED, "common table expression");
+        assert_eq!(CteMaterialized::NOTMATERIALIZED.name(), "NOT MATERIALIZED");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            Cte::parse(&source, 0),
+            Err(err) if err.cause == "missing identifier"
+        ));
+        // name only
+        let text = "t AS (SELECT a FROM u)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            Cte::parse(&source, 0),
+            Ok((cte, index)) if eq_ignore_span(&cte.name, &IdentExpr::new("t")) && cte.columns.is_none()
+                && cte.materialized.is_none() && cte.span.range() == (0..22) && index == 8
+        ));
+        // column list and hint
+        let text = "t (x, y) AS NOT MATERIALIZED (SELECT a, b FROM u)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            Cte::parse(&source, 0),
+            Ok((cte, index)) if cte.columns.as_ref().map(|columns| columns.items.len()) == Some(2)
+                && cte.materialized == Some(CteMaterialized::NOTMATERIALIZED) && index == 17
+        ));
+        // missing AS
+        let text = "t (SELECT a FROM u)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            Cte::parse(&source, 0),
+            Err(err) if err.cause == "missing AS"
+        ));
+        let text = "t (x) (SELECT a FROM u)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            Cte::parse(&source, 0),
+            Err(err) if err.cause == "missing AS"
+        ));
+        // NOT without MATERIALIZED
+        let text = "t AS NOT (SELECT a FROM u)";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            Cte::parse(&source, 0),
+            Err(err) if err.cause == "missing MATERIALIZED"
+        ));
+        // the query needs parentheses
+        let text = "t AS SELECT a FROM u";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            Cte::parse(&source, 0),
+            Err(err) if err.cause == "missing subquery"
+        ));
+    }
+}
@@ -1,55 +1,113 @@
+use std::fmt;
+
 use rsdb::{Named, NamedEnum};
 
 use crate::sql::{
     err::SyntaxError,
-    expr::name::NameExpr,
-    lexer::{lexer::Lexer, mat::LexerMatch},
-    parser::{LexerParser, SyntaxPattern},
+    expr::{alias::AliasExpr, name::NameExpr, subquery::SubqueryExpr},
+    format::{fmt_sql, FormatOptions, ToSql},
+    frag::join::JoinExpr,
+    lexer::lexer::Lexer,
+    parser::{LexerParser, LexerStart, SyntaxPattern},
+    span::{Span, Spanned},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum FromItem {
-    NAME(NameExpr),
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum FromItem<'a> {
+    NAME(NameExpr<'a>),
+    JOIN(JoinExpr<'a>),
+    SUBQUERY(SubqueryExpr<'a>),
 }
 
-impl Named for FromItem {
+impl Named for FromItem<'_> {
     const NAMED: &'static str = "from item";
 }
 
-impl NamedEnum for FromItem {
+impl LexerStart for FromItem<'_> {
+    const START: &'static [&'static str] = &["identifier", "'('"];
+}
+
+impl NamedEnum for FromItem<'_> {
     fn name(&self) -> &'static str {
         match self {
             Self::NAME(_) => NameExpr::NAMED,
+            Self::JOIN(_) => JoinExpr::NAMED,
+            Self::SUBQUERY(_) => SubqueryExpr::NAMED,
+        }
+    }
+}
+
+impl Spanned for FromItem<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::NAME(expr) => expr.span(),
+            Self::JOIN(expr) => expr.span(),
+            Self::SUBQUERY(expr) => expr.span(),
         }
     }
 }
 
-impl LexerParser for FromItem {
-    fn parse(source: &SyntaxPattern, index: usize) -> Result<(Self, usize), SyntaxError> {
+impl<'a> LexerParser<'a> for FromItem<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
         match source.items.get(index) {
-            Some(lexer) => match lexer {
-                Lexer::NAME(_) => match NameExpr::parse(source, index) {
-                    Ok((expr, end_index)) => Ok((FromItem::NAME(expr), end_index)),
+            Some(lexer) if lexer.is_ident() => match NameExpr::parse(source, index) {
+                Ok((expr, end_index)) => Ok((FromItem::NAME(expr), end_index)),
+                Err(err) => Err(err),
+            },
+            // (SELECT ...), a derived table
+            Some(Lexer::LPAREN(_)) if SubqueryExpr::is_start(source, index) => {
+                match SubqueryExpr::parse(source, index) {
+                    Ok((expr, end_index)) => Ok((FromItem::SUBQUERY(expr), end_index)),
                     Err(err) => Err(err),
+                }
+            }
+            // (a JOIN b), the parentheses must hold a join
+            Some(Lexer::LPAREN(_)) => match JoinExpr::parse_joined(source, index + 1)? {
+                (
+                    AliasExpr {
+                        value: FromItem::JOIN(join),
+                        alias: None,
+                        ..
+                    },
+                    end_index,
+                ) => match source.items.get(end_index) {
+                    Some(Lexer::RPAREN(_)) => Ok((FromItem::JOIN(join), end_index + 1)),
+                    _ => Err(source.missing(end_index, ")", &["')'"])),
                 },
-                _ => Err(SyntaxError::new_missing(lexer.value(), FromItem::NAMED)),
+                (_, end_index) => Err(source.missing(end_index, "JOIN", &["JOIN"])),
             },
-            None => Err(SyntaxError::new_missing(
-                LexerMatch::new_eof(&source.text),
-                FromItem::NAMED,
-            )),
+            _ => Err(source.missing(index, FromItem::NAMED, FromItem::START)),
+        }
+    }
+}
+
+impl ToSql for FromItem<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        match self {
+            Self::NAME(expr) => expr.to_sql(options),
+            Self::JOIN(expr) => expr.to_sql(options),
+            Self::SUBQUERY(expr) => expr.to_sql(options),
         }
     }
 }
 
+impl fmt::Display for FromItem<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rsdb::{Named, NamedEnum};
 
     use crate::sql::{
-        expr::name::NameExpr,
-        lexer::{lexer::Lexer, mat::LexerMatch},
-        parser::{LexerParser, SyntaxPattern},
+        expr::{ident::IdentExpr, name::NameExpr},
+        lexer::{lexer::Lexer, mat::LexerMatch, pattern::LexerPattern},
+        parser::{parse_stmt, LexerParser, SyntaxPattern},
+        span::eq_ignore_span,
     };
 
     use super::FromItem;
@@ -84,7 +142,42 @@ mod tests {
         );
         assert!(matches!(
             FromItem::parse(&source, 0),
-            Ok((item,index)) if matches!(&item, FromItem::NAME(expr) if expr.value == "3210") && index == 1
+            Ok((item,index)) if matches!(&item, FromItem::NAME(expr) if eq_ignore_span(expr, &NameExpr::new("3210"))) && index == 1
+        ));
+        // contain from item -> quoted name expression
+        source = SyntaxPattern::new(
+            "`Order Details`",
+            vec![Lexer::QUOTED(LexerMatch::new_full_match("`Order Details`"))],
+        );
+        assert!(matches!(
+            FromItem::parse(&source, 0),
+            Ok((item, index)) if eq_ignore_span(&item, &FromItem::NAME(NameExpr::new_quoted("Order Details"))) && index == 1
+        ));
+        // contain from item -> qualified name expression
+        let text = "public.users";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            FromItem::parse(&source, 0),
+            Ok((item, index)) if eq_ignore_span(&item, &FromItem::NAME(NameExpr::new_parts(vec![
+                IdentExpr::new("public"),
+                IdentExpr::new("users"),
+            ]))) && index == 3
+        ));
+    }
+
+    #[test]
+    fn it_parse_stmt() {
+        // a subquery in FROM needs an alias after AS
+        assert!(matches!(
+            parse_stmt("SELECT a FROM (SELECT a FROM t)"),
+            Err(err) if err.cause == "subquery in FROM must have an alias"
+                && err.start == 14 && err.end == 31
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT x.a FROM (SELECT a FROM t) x"),
+            Err(err) if err.cause == "AS is required before a subquery alias"
+                && err.start == 34 && err.end == 35
         ));
+        assert!(parse_stmt("SELECT x.a FROM (SELECT a FROM t) AS x").is_ok());
     }
 }
@@ -0,0 +1,458 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    err::SyntaxError,
+    expr::{alias::AliasExpr, expr::Expr, ident::IdentExpr, items::ItemsExpr},
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+use super::from_item::FromItem;
+
+/// Join type, `INNER` and `OUTER` are optional keywords
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JoinKind {
+    INNER,
+    LEFT,
+    RIGHT,
+    FULL,
+    CROSS,
+}
+
+impl NamedEnum for JoinKind {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::INNER => "INNER",
+            Self::LEFT => "LEFT",
+            Self::RIGHT => "RIGHT",
+            Self::FULL => "FULL",
+            Self::CROSS => "CROSS",
+        }
+    }
+}
+
+/// Join condition
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum JoinConstraint<'a> {
+    ON(Expr<'a>),
+    USING(ItemsExpr<IdentExpr<'a>>),
+}
+
+impl NamedEnum for JoinConstraint<'_> {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::ON(_) => "ON",
+            Self::USING(_) => "USING",
+        }
+    }
+}
+
+impl ToSql for JoinConstraint<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        match self {
+            Self::ON(expr) => format!("{} {}", options.keyword("ON"), expr.to_sql(options)),
+            Self::USING(items) => {
+                format!("{} ({})", options.keyword("USING"), items.to_sql(options))
+            }
+        }
+    }
+}
+
+/// Join Expression
+///
+/// `left [NATURAL] kind JOIN right [ON expr | USING (cols)]`, joins are left-associative,
+/// so a chain of joins nests in `left`. `CROSS` and `NATURAL` joins have no constraint,
+/// the other joins need one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JoinExpr<'a> {
+    pub left: Box<AliasExpr<'a, FromItem<'a>>>,
+    pub kind: JoinKind,
+    pub natural: bool,
+    pub right: Box<AliasExpr<'a, FromItem<'a>>>,
+    pub constraint: Option<JoinConstraint<'a>>,
+    pub span: Span,
+}
+
+impl Named for JoinExpr<'_> {
+    const NAMED: &'static str = "join";
+}
+
+impl Spanned for JoinExpr<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> JoinExpr<'a> {
+    /// Creates a new join
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let expr = JoinExpr::new(
+    ///     AliasExpr::new_without_alias(FromItem::NAME(NameExpr::new("a"))),
+    ///     JoinKind::CROSS,
+    ///     AliasExpr::new_without_alias(FromItem::NAME(NameExpr::new("b"))),
+    ///     None,
+    /// );
+    /// ```
+    pub fn new(
+        left: AliasExpr<'a, FromItem<'a>>,
+        kind: JoinKind,
+        right: AliasExpr<'a, FromItem<'a>>,
+        constraint: Option<JoinConstraint<'a>>,
+    ) -> Self {
+        JoinExpr {
+            left: Box::new(left),
+            kind,
+            natural: false,
+            right: Box::new(right),
+            constraint,
+            span: Span::default(),
+        }
+    }
+
+    /// Parses a from item followed by any number of joins
+    pub fn parse_joined(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+    ) -> Result<(AliasExpr<'a, FromItem<'a>>, usize), SyntaxError<'a>> {
+        let (mut left, mut end_index) = Self::parse_item(source, index)?;
+        while let Some((kind, natural, join_end_index)) = Self::parse_kind(source, end_index)? {
+            let (right, right_end_index) = Self::parse_item(source, join_end_index)?;
+            let (constraint, constraint_end_index) = match (kind, natural) {
+                (JoinKind::CROSS, _) | (_, true) => (None, right_end_index),
+                _ => {
+                    let (constraint, end_index) = Self::parse_constraint(source, right_end_index)?;
+                    (Some(constraint), end_index)
+                }
+            };
+            let join = JoinExpr {
+                natural,
+                span: source.span(index, constraint_end_index),
+                ..JoinExpr::new(left, kind, right, constraint)
+            };
+            left = AliasExpr {
+                span: join.span,
+                ..AliasExpr::new_without_alias(FromItem::JOIN(join))
+            };
+            end_index = constraint_end_index;
+        }
+        Ok((left, end_index))
+    }
+
+    /// A from item with its alias, a subquery needs one
+    fn parse_item(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+    ) -> Result<(AliasExpr<'a, FromItem<'a>>, usize), SyntaxError<'a>> {
+        let (item, end_index) = AliasExpr::<FromItem>::parse(source, index)?;
+        match (&item.value, &item.alias, source.items.get(end_index)) {
+            // (SELECT ...) x, an alias is only taken after AS
+            (FromItem::SUBQUERY(_), None, Some(lexer)) if lexer.is_ident() => Err(source
+                .invalid(
+                    source.span(end_index, end_index + 1),
+                    "AS is required before a subquery alias",
+                )
+                .with_help("write the alias as (SELECT ...) AS x")),
+            (FromItem::SUBQUERY(subquery), None, _) => Err(source
+                .invalid(subquery.span, "subquery in FROM must have an alias")
+                .with_help("add an alias like (SELECT ...) AS x")),
+            _ => Ok((item, end_index)),
+        }
+    }
+
+    /// `[NATURAL] [INNER | {LEFT | RIGHT | FULL} [OUTER] | CROSS] JOIN`, `None` if no join starts
+    fn parse_kind(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+    ) -> Result<Option<(JoinKind, bool, usize)>, SyntaxError<'a>> {
+        let (natural, kind_index) = match source.items.get(index) {
+            Some(Lexer::NATURAL(_)) => (true, index + 1),
+            _ => (false, index),
+        };
+        let (kind, join_index) = match source.items.get(kind_index) {
+            Some(Lexer::JOIN(_)) => (JoinKind::INNER, kind_index),
+            Some(Lexer::INNER(_)) => (JoinKind::INNER, kind_index + 1),
+            Some(Lexer::CROSS(_)) if !natural => (JoinKind::CROSS, kind_index + 1),
+            Some(Lexer::LEFT(_) | Lexer::RIGHT(_) | Lexer::FULL(_)) => {
+                let kind = match source.items.get(kind_index) {
+                    Some(Lexer::LEFT(_)) => JoinKind::LEFT,
+                    Some(Lexer::RIGHT(_)) => JoinKind::RIGHT,
+                    _ => JoinKind::FULL,
+                };
+                match source.items.get(kind_index + 1) {
+                    Some(Lexer::OUTER(_)) => (kind, kind_index + 2),
+                    _ => {
+                        source.expect(kind_index + 1, &["OUTER"]);
+                        (kind, kind_index + 1)
+                    }
+                }
+            }
+            _ if natural => {
+                return Err(source.missing(
+                    kind_index,
+                    Self::NAMED,
+                    &["JOIN", "INNER", "LEFT", "RIGHT", "FULL"],
+                ))
+            }
+            _ => {
+                source.expect(
+                    index,
+                    &["JOIN", "INNER", "LEFT", "RIGHT", "FULL", "CROSS", "NATURAL"],
+                );
+                return Ok(None);
+            }
+        };
+        match source.items.get(join_index) {
+            Some(Lexer::JOIN(_)) => Ok(Some((kind, natural, join_index + 1))),
+            _ => Err(source.missing(join_index, "JOIN", &["JOIN"])),
+        }
+    }
+
+    /// `ON expr` or `USING (col, ...)`
+    fn parse_constraint(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+    ) -> Result<(JoinConstraint<'a>, usize), SyntaxError<'a>> {
+        match source.items.get(index) {
+            Some(Lexer::ON(_)) => {
+                let (expr, end_index) = Expr::parse(source, index + 1)?;
+                Ok((JoinConstraint::ON(expr), end_index))
+            }
+            Some(Lexer::USING(_)) => match source.items.get(index + 1) {
+                Some(Lexer::LPAREN(_)) => {
+                    let (items, end_index) = ItemsExpr::<IdentExpr>::parse(source, index + 2)?;
+                    if let Some(err) = items.min_len_check(source, index + 2, 1) {
+                        return Err(err);
+                    }
+                    match source.items.get(end_index) {
+                        Some(Lexer::RPAREN(_)) => Ok((JoinConstraint::USING(items), end_index + 1)),
+                        _ => Err(source.missing(end_index, ")", &["')'"])),
+                    }
+                }
+                _ => Err(source.missing(index + 1, "(", &["'('"])),
+            },
+            _ => Err(source.missing(index, "join constraint", &["ON", "USING"])),
+        }
+    }
+}
+
+impl ToSql for JoinExpr<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let mut join = vec![];
+        if self.natural {
+            join.push(options.keyword("NATURAL"));
+        }
+        if self.kind != JoinKind::INNER {
+            join.push(options.keyword(self.kind.name()));
+        }
+        join.push(options.keyword("JOIN"));
+        let mut sql = format!(
+            "{} {} {}",
+            self.left.to_sql_nested(options, false),
+            join.join(" "),
+            self.right.to_sql_nested(options, true)
+        );
+        if let Some(constraint) = &self.constraint {
+            sql.push(' ');
+            sql.push_str(&constraint.to_sql(options));
+        }
+        sql
+    }
+}
+
+impl fmt::Display for JoinExpr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+impl AliasExpr<'_, FromItem<'_>> {
+    /// Writes the from item, a join is put in parentheses if it has an alias or it is `nested`
+    /// on the right side of another join, a join on the left side is kept as is
+    pub fn to_sql_nested(&self, options: &FormatOptions, nested: bool) -> String {
+        match (&self.value, &self.alias) {
+            (FromItem::JOIN(join), Some(alias)) => format!(
+                "({}) {} {}",
+                join.to_sql(options),
+                options.keyword("AS"),
+                alias.to_sql(options)
+            ),
+            (FromItem::JOIN(join), None) if nested => format!("({})", join.to_sql(options)),
+            _ => self.to_sql(options),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::{
+        expr::{
+            alias::AliasExpr, binary::BinaryOp, expr::Expr, ident::IdentExpr, items::ItemsExpr,
+            name::NameExpr,
+        },
+        format::FormatOptions,
+        frag::from_item::FromItem,
+        lexer::pattern::LexerPattern,
+        parser::{parse_stmt, SyntaxPattern},
+        span::eq_ignore_span,
+    };
+
+    use super::{JoinConstraint, JoinExpr, JoinKind};
+
+    fn table(name: &str) -> AliasExpr<'_, FromItem<'_>> {
+        AliasExpr::new_without_alias(FromItem::NAME(NameExpr::new(name)))
+    }
+
+    fn parse(text: &str) -> Result<AliasExpr<'_, FromItem<'_>>, String> {
+        let source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        match JoinExpr::parse_joined(&source, 0) {
+            Ok((item, index)) if index == source.items.len() => Ok(item),
+            Ok((_, index)) => Err(format!("stopped at {}", index)),
+            Err(err) => Err(err.cause),
+        }
+    }
+
+    #[test]
+    fn it_name() {
+        assert_eq!(JoinExpr::NAMED, "join");
+        assert_eq!(JoinKind::FULL.name(), "FULL");
+        assert_eq!(
+            JoinConstraint::USING(ItemsExpr::new(vec![])).name(),
+            "USING"
+        );
+    }
+
+    #[test]
+    fn it_parse() {
+        // a single table
+        assert!(eq_ignore_span(
+            &parse("a AS x").unwrap().alias,
+            &Some(IdentExpr::new("x"))
+        ));
+        // join kinds
+        for (text, kind, natural) in [
+            ("a JOIN b ON c", JoinKind::INNER, false),
+            ("a INNER JOIN b ON c", JoinKind::INNER, false),
+            ("a LEFT JOIN b ON c", JoinKind::LEFT, false),
+            ("a LEFT OUTER JOIN b ON c", JoinKind::LEFT, false),
+            ("a RIGHT JOIN b USING (c)", JoinKind::RIGHT, false),
+            ("a FULL OUTER JOIN b USING (c)", JoinKind::FULL, false),
+            ("a CROSS JOIN b", JoinKind::CROSS, false),
+            ("a NATURAL JOIN b", JoinKind::INNER, true),
+            ("a NATURAL LEFT JOIN b", JoinKind::LEFT, true),
+        ] {
+            let item = parse(text).unwrap();
+            assert!(
+                matches!(&item.value, FromItem::JOIN(join) if join.kind == kind && join.natural == natural),
+                "{}",
+                text
+            );
+            assert_eq!(item.span.range(), 0..text.len());
+        }
+        // constraints
+        assert!(matches!(
+            parse("a JOIN b ON a.id = b.id").unwrap().value,
+            FromItem::JOIN(join) if matches!(&join.constraint, Some(JoinConstraint::ON(Expr::BINARY(expr))) if expr.op == BinaryOp::EQ)
+        ));
+        assert!(matches!(
+            parse("a JOIN b USING (c, d)").unwrap().value,
+            FromItem::JOIN(join) if eq_ignore_span(&join.constraint, &Some(JoinConstraint::USING(ItemsExpr::new(vec![
+                IdentExpr::new("c"),
+                IdentExpr::new("d"),
+            ]))))
+        ));
+        // errors
+        assert_eq!(parse("a JOIN b").unwrap_err(), "missing join constraint");
+        assert_eq!(parse("a LEFT b ON c").unwrap_err(), "missing JOIN");
+        assert_eq!(parse("a NATURAL CROSS JOIN b").unwrap_err(), "missing join");
+        assert_eq!(parse("a JOIN b USING c").unwrap_err(), "missing (");
+        assert_eq!(
+            parse("a JOIN b USING ()").unwrap_err(),
+            "missing identifier"
+        );
+        assert_eq!(parse("a CROSS JOIN b ON c").unwrap_err(), "stopped at 4");
+        assert_eq!(parse("(a)").unwrap_err(), "missing JOIN");
+        // a subquery needs an alias after AS
+        assert_eq!(
+            parse("(SELECT a FROM t)").unwrap_err(),
+            "subquery in FROM must have an alias"
+        );
+        assert_eq!(
+            parse("(SELECT a FROM t) x").unwrap_err(),
+            "AS is required before a subquery alias"
+        );
+        assert!(eq_ignore_span(
+            &parse("(SELECT a FROM t) AS x").unwrap().alias,
+            &Some(IdentExpr::new("x"))
+        ));
+    }
+
+    #[test]
+    fn it_associativity() {
+        // a JOIN b JOIN c -> (a JOIN b) JOIN c
+        let item = parse("a CROSS JOIN b CROSS JOIN c").unwrap();
+        let left = JoinExpr::new(table("a"), JoinKind::CROSS, table("b"), None);
+        let expected = JoinExpr::new(
+            AliasExpr::new_without_alias(FromItem::JOIN(left)),
+            JoinKind::CROSS,
+            table("c"),
+            None,
+        );
+        assert!(eq_ignore_span(&item.value, &FromItem::JOIN(expected)));
+        // a JOIN (b JOIN c)
+        let item = parse("a CROSS JOIN (b CROSS JOIN c)").unwrap();
+        let right = JoinExpr::new(table("b"), JoinKind::CROSS, table("c"), None);
+        let expected = JoinExpr::new(
+            table("a"),
+            JoinKind::CROSS,
+            AliasExpr::new_without_alias(FromItem::JOIN(right)),
+            None,
+        );
+        assert!(eq_ignore_span(&item.value, &FromItem::JOIN(expected)));
+        // parentheses are kept where they change the tree
+        let options = FormatOptions::compact();
+        for text in [
+            "a CROSS JOIN b CROSS JOIN c",
+            "a CROSS JOIN (b CROSS JOIN c)",
+            "(a CROSS JOIN b) AS x CROSS JOIN c",
+            "a LEFT JOIN (b NATURAL RIGHT JOIN c) AS x ON a.id = x.id",
+        ] {
+            assert_eq!(parse(text).unwrap().to_sql_nested(&options, false), text);
+        }
+    }
+
+    #[test]
+    fn it_parse_stmt() {
+        assert!(matches!(
+            parse_stmt("SELECT t.a, u.b FROM t LEFT JOIN u ON t.id = u.id CROSS JOIN v"),
+            Ok(stmt) if stmt.to_string()
+                == "SELECT t.a, u.b FROM t LEFT JOIN u ON t.id = u.id CROSS JOIN v"
+        ));
+        // every join starter is expected after a from item
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t garbage"),
+            Err(err) if ["CROSS", "FULL", "INNER", "JOIN", "LEFT", "NATURAL", "RIGHT"]
+                .iter()
+                .all(|name| err.expected.contains(name))
+        ));
+        // there is no natural cross join
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t NATURAL CROSS JOIN u"),
+            Err(err) if err.cause == "expected one of FULL, INNER, JOIN, LEFT, RIGHT, got CROSS"
+        ));
+    }
+}
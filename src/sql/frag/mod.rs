@@ -1,2 +1,6 @@
+pub mod cte;
 pub mod from_item;
+pub mod join;
+pub mod order_by_item;
+pub mod row_count;
 pub mod select_item;
@@ -0,0 +1,282 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    err::SyntaxError,
+    expr::{expr::Expr, ident::IdentExpr, literal::LiteralExpr},
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, LexerStart, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+/// What an order by item sorts by
+///
+/// A bare integer refers to a select item by its 1-based position, a single identifier
+/// matching a select alias refers to that item, see `OrderByClause::resolve`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum OrderByKey<'a> {
+    EXPR(Expr<'a>),
+    ORDINAL(usize, Span),
+    ALIAS(IdentExpr<'a>),
+}
+
+impl NamedEnum for OrderByKey<'_> {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::EXPR(_) => Expr::NAMED,
+            Self::ORDINAL(..) => "ORDINAL",
+            Self::ALIAS(_) => "ALIAS",
+        }
+    }
+}
+
+impl Spanned for OrderByKey<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::EXPR(expr) => expr.span(),
+            Self::ORDINAL(_, span) => *span,
+            Self::ALIAS(ident) => ident.span(),
+        }
+    }
+}
+
+impl ToSql for OrderByKey<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        match self {
+            Self::EXPR(expr) => expr.to_sql(options),
+            Self::ORDINAL(position, _) => position.to_string(),
+            Self::ALIAS(ident) => ident.to_sql(options),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrderDirection {
+    ASC,
+    DESC,
+}
+
+impl NamedEnum for OrderDirection {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::ASC => "ASC",
+            Self::DESC => "DESC",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NullsOrder {
+    FIRST,
+    LAST,
+}
+
+impl NamedEnum for NullsOrder {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::FIRST => "FIRST",
+            Self::LAST => "LAST",
+        }
+    }
+}
+
+/// Order By Item
+///
+/// `key [ASC | DESC] [NULLS FIRST | NULLS LAST]`, unset options keep the database default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderByItem<'a> {
+    pub key: OrderByKey<'a>,
+    pub direction: Option<OrderDirection>,
+    pub nulls: Option<NullsOrder>,
+    pub span: Span,
+}
+
+impl Named for OrderByItem<'_> {
+    const NAMED: &'static str = "order by item";
+}
+
+impl LexerStart for OrderByItem<'_> {
+    const START: &'static [&'static str] = Expr::START;
+}
+
+impl Spanned for OrderByItem<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> LexerParser<'a> for OrderByItem<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        let (expr, mut end_index) = Expr::parse(source, index)?;
+        let key = match expr {
+            Expr::LITERAL(LiteralExpr::INTEGER(value, span)) => match value.parse::<usize>() {
+                Ok(position) => OrderByKey::ORDINAL(position, span),
+                Err(_) => {
+                    return Err(source.invalid(
+                        span,
+                        &format!("ORDER BY position {} is out of range", value),
+                    ))
+                }
+            },
+            expr => OrderByKey::EXPR(expr),
+        };
+
+        let direction = match source.items.get(end_index) {
+            Some(Lexer::ASC(_)) => Some(OrderDirection::ASC),
+            Some(Lexer::DESC(_)) => Some(OrderDirection::DESC),
+            _ => {
+                source.expect(end_index, &["ASC", "DESC"]);
+                None
+            }
+        };
+        if direction.is_some() {
+            end_index += 1;
+        }
+
+        let nulls = match source.items.get(end_index) {
+            Some(Lexer::NULLS(_)) => match source.items.get(end_index + 1) {
+                Some(Lexer::FIRST(_)) => Some(NullsOrder::FIRST),
+                Some(Lexer::LAST(_)) => Some(NullsOrder::LAST),
+                _ => return Err(source.missing(end_index + 1, "nulls order", &["FIRST", "LAST"])),
+            },
+            _ => {
+                source.expect(end_index, &["NULLS"]);
+                None
+            }
+        };
+        if nulls.is_some() {
+            end_index += 2;
+        }
+
+        Ok((
+            OrderByItem {
+                key,
+                direction,
+                nulls,
+                span: source.span(index, end_index),
+            },
+            end_index,
+        ))
+    }
+}
+
+impl<'a> OrderByItem<'a> {
+    /// Creates a new order by item without direction and null ordering
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let item = OrderByItem::new(OrderByKey::ORDINAL(1, Span::default()));
+    /// ```
+    pub fn new(key: OrderByKey<'a>) -> Self {
+        OrderByItem {
+            key,
+            direction: None,
+            nulls: None,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for OrderByItem<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let mut sql = self.key.to_sql(options);
+        if let Some(direction) = self.direction {
+            sql.push(' ');
+            sql.push_str(&options.keyword(direction.name()));
+        }
+        if let Some(nulls) = self.nulls {
+            sql.push(' ');
+            sql.push_str(&options.keyword("NULLS"));
+            sql.push(' ');
+            sql.push_str(&options.keyword(nulls.name()));
+        }
+        sql
+    }
+}
+
+impl fmt::Display for OrderByItem<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::{
+        expr::{expr::Expr, name::NameExpr},
+        lexer::pattern::LexerPattern,
+        parser::{LexerParser, SyntaxPattern},
+        span::{eq_ignore_span, Span},
+    };
+
+    use super::{NullsOrder, OrderByItem, OrderByKey, OrderDirection};
+
+    #[test]
+    fn it_name() {
+        assert_eq!(OrderByItem::NAMED, "order by item");
+        assert_eq!(OrderByKey::ORDINAL(1, Span::default()).name(), "ORDINAL");
+        assert_eq!(OrderDirection::DESC.name(), "DESC");
+        assert_eq!(NullsOrder::LAST.name(), "LAST");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            OrderByItem::parse(&source, 0),
+            Err(err) if err.cause == "missing expression"
+        ));
+        // expression without options
+        let text = "a, b";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            OrderByItem::parse(&source, 0),
+            Ok((item, index)) if eq_ignore_span(&item, &OrderByItem::new(OrderByKey::EXPR(Expr::NAME(NameExpr::new("a"))))) && index == 1
+        ));
+        // ordinal with direction and null ordering
+        let text = "2 desc nulls first";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            OrderByItem::parse(&source, 0),
+            Ok((item, index)) if eq_ignore_span(&item, &OrderByItem {
+                direction: Some(OrderDirection::DESC),
+                nulls: Some(NullsOrder::FIRST),
+                ..OrderByItem::new(OrderByKey::ORDINAL(2, Span::default()))
+            }) && item.span.range() == (0..18) && index == 4
+        ));
+        // non reserved keywords are identifiers
+        let text = "last ASC";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            OrderByItem::parse(&source, 0),
+            Ok((item, index)) if eq_ignore_span(&item.key, &OrderByKey::EXPR(Expr::NAME(NameExpr::new("last")))) && item.direction == Some(OrderDirection::ASC) && index == 2
+        ));
+        // NULLS without FIRST or LAST
+        let text = "a NULLS";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            OrderByItem::parse(&source, 0),
+            Err(err) if err.cause == "missing nulls order"
+        ));
+        // position does not fit
+        let text = "99999999999999999999999";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            OrderByItem::parse(&source, 0),
+            Err(err) if err.cause == "ORDER BY position 99999999999999999999999 is out of range" && err.start == 0
+        ));
+    }
+}
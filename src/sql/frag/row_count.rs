@@ -0,0 +1,123 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    err::SyntaxError,
+    expr::{literal::LiteralExpr, param::ParamExpr},
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+/// Row Count of `LIMIT`, `OFFSET` and `FETCH`, an integer or a bind parameter
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum RowCount<'a> {
+    LITERAL(LiteralExpr<'a>),
+    PARAM(ParamExpr<'a>),
+}
+
+impl Named for RowCount<'_> {
+    const NAMED: &'static str = "row count";
+}
+
+impl NamedEnum for RowCount<'_> {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::LITERAL(_) => LiteralExpr::NAMED,
+            Self::PARAM(_) => ParamExpr::NAMED,
+        }
+    }
+}
+
+impl Spanned for RowCount<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::LITERAL(expr) => expr.span(),
+            Self::PARAM(expr) => expr.span(),
+        }
+    }
+}
+
+impl<'a> LexerParser<'a> for RowCount<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        match source.items.get(index) {
+            Some(Lexer::INTEGER(_)) => match LiteralExpr::parse(source, index) {
+                Ok((expr, end_index)) => Ok((RowCount::LITERAL(expr), end_index)),
+                Err(err) => Err(err),
+            },
+            Some(Lexer::PARAM(_)) => match ParamExpr::parse(source, index) {
+                Ok((expr, end_index)) => Ok((RowCount::PARAM(expr), end_index)),
+                Err(err) => Err(err),
+            },
+            _ => Err(source.missing(index, Self::NAMED, &["integer", "parameter"])),
+        }
+    }
+}
+
+impl ToSql for RowCount<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        match self {
+            Self::LITERAL(expr) => expr.to_sql(options),
+            Self::PARAM(expr) => expr.to_sql(options),
+        }
+    }
+}
+
+impl fmt::Display for RowCount<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::{
+        expr::{literal::LiteralExpr, param::ParamExpr},
+        lexer::pattern::LexerPattern,
+        parser::{LexerParser, SyntaxPattern},
+        span::{eq_ignore_span, Span},
+    };
+
+    use super::RowCount;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(RowCount::NAMED, "row count");
+        assert_eq!(RowCount::PARAM(ParamExpr::new("?")).name(), "parameter");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            RowCount::parse(&source, 0),
+            Err(err) if err.cause == "missing row count"
+        ));
+        // only integers are row counts
+        let text = "1.5";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            RowCount::parse(&source, 0),
+            Err(err) if err.cause == "missing row count"
+        ));
+        let text = "10";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            RowCount::parse(&source, 0),
+            Ok((count, index)) if eq_ignore_span(&count, &RowCount::LITERAL(LiteralExpr::INTEGER("10".into(), Span::default()))) && index == 1
+        ));
+        let text = ":size";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            RowCount::parse(&source, 0),
+            Ok((count, index)) if eq_ignore_span(&count, &RowCount::PARAM(ParamExpr::new(":size"))) && index == 1
+        ));
+    }
+}
@@ -1,58 +1,130 @@
+use std::fmt;
+
 use rsdb::{Named, NamedEnum};
 
 use crate::sql::{
     err::SyntaxError,
-    expr::name::NameExpr,
-    lexer::{lexer::Lexer, mat::LexerMatch},
-    parser::{LexerParser, SyntaxPattern},
+    expr::{expr::Expr, name::NameExpr},
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, LexerStart, SyntaxPattern},
+    span::{Span, Spanned},
 };
 
+/// Select Item
+///
+/// An expression, `*` or `name.*`. A `*` starting an item is the wildcard, after an
+/// operand it multiplies, e.g. `a * 2`.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum SelectItem {
-    NAME(NameExpr),
-    STAR,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum SelectItem<'a> {
+    EXPR(Expr<'a>),
+    STAR(Span),
+    QUALIFIEDSTAR(NameExpr<'a>, Span),
 }
 
-impl Named for SelectItem {
+impl Named for SelectItem<'_> {
     const NAMED: &'static str = "select item";
 }
 
-impl NamedEnum for SelectItem {
+impl LexerStart for SelectItem<'_> {
+    const START: &'static [&'static str] = &[
+        "identifier",
+        "literal",
+        "parameter",
+        "'('",
+        "NOT",
+        "'-'",
+        "'*'",
+    ];
+}
+
+impl NamedEnum for SelectItem<'_> {
     fn name(&self) -> &'static str {
         match self {
-            Self::NAME(_) => NameExpr::NAMED,
-            Self::STAR => "STAR",
+            Self::EXPR(expr) => expr.name(),
+            Self::STAR(_) => "STAR",
+            Self::QUALIFIEDSTAR(..) => "QUALIFIED STAR",
+        }
+    }
+}
+
+impl Spanned for SelectItem<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::EXPR(expr) => expr.span(),
+            Self::STAR(span) | Self::QUALIFIEDSTAR(_, span) => *span,
+        }
+    }
+}
+
+impl<'a> LexerParser<'a> for SelectItem<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        match (source.items.get(index), source.items.get(index + 1)) {
+            (Some(Lexer::STAR(_)), _) => {
+                Ok((SelectItem::STAR(source.span(index, index + 1)), index + 1))
+            }
+            // name.*
+            (Some(lexer), Some(Lexer::DOT(_))) if lexer.is_ident() => {
+                let (expr, end_index) = NameExpr::parse(source, index)?;
+                match (source.items.get(end_index), source.items.get(end_index + 1)) {
+                    (Some(Lexer::DOT(_)), Some(Lexer::STAR(_))) => Ok((
+                        SelectItem::QUALIFIEDSTAR(expr, source.span(index, end_index + 2)),
+                        end_index + 2,
+                    )),
+                    _ => Self::parse_expr(source, index),
+                }
+            }
+            (None, _) => Err(source.missing(index, SelectItem::NAMED, SelectItem::START)),
+            _ => {
+                source.expect(index, &["'*'"]);
+                Self::parse_expr(source, index)
+            }
         }
     }
 }
 
-impl LexerParser for SelectItem {
-    fn parse(source: &SyntaxPattern, index: usize) -> Result<(Self, usize), SyntaxError> {
-        match source.items.get(index) {
-            Some(lexer) => match lexer {
-                Lexer::NAME(_) => match NameExpr::parse(source, index) {
-                    Ok((expr, end_index)) => Ok((SelectItem::NAME(expr), end_index)),
-                    Err(err) => Err(err),
-                },
-                Lexer::STAR(_) => Ok((SelectItem::STAR, index + 1)),
-                _ => Err(SyntaxError::new_missing(lexer.value(), SelectItem::NAMED)),
-            },
-            None => Err(SyntaxError::new_missing(
-                LexerMatch::new_eof(&source.text),
-                SelectItem::NAMED,
-            )),
+impl<'a> SelectItem<'a> {
+    fn parse_expr(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        match Expr::parse(source, index) {
+            Ok((expr, end_index)) => Ok((SelectItem::EXPR(expr), end_index)),
+            Err(err) => Err(err),
         }
     }
 }
 
+impl ToSql for SelectItem<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        match self {
+            Self::EXPR(expr) => expr.to_sql(options),
+            Self::STAR(_) => "*".to_owned(),
+            Self::QUALIFIEDSTAR(expr, _) => format!("{}.*", expr.to_sql(options)),
+        }
+    }
+}
+
+impl fmt::Display for SelectItem<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rsdb::{Named, NamedEnum};
 
     use crate::sql::{
-        expr::name::NameExpr,
-        lexer::{lexer::Lexer, mat::LexerMatch},
+        expr::{
+            aggregate::AggregateExpr, binary::BinaryExpr, binary::BinaryOp, expr::Expr,
+            ident::IdentExpr, literal::LiteralExpr, name::NameExpr,
+        },
+        lexer::{lexer::Lexer, mat::LexerMatch, pattern::LexerPattern},
         parser::{LexerParser, SyntaxPattern},
+        span::{eq_ignore_span, Span, Spanned},
     };
 
     use super::SelectItem;
@@ -61,10 +133,22 @@ mod tests {
     fn it_name() {
         assert_eq!(SelectItem::NAMED, "select item");
         assert_eq!(
-            SelectItem::NAME(NameExpr::new("")).name(),
+            SelectItem::EXPR(Expr::NAME(NameExpr::new(""))).name(),
             "name expression"
         );
-        assert_eq!(SelectItem::STAR.name(), "STAR");
+        assert_eq!(
+            SelectItem::EXPR(Expr::LITERAL(LiteralExpr::INTEGER(
+                "".into(),
+                Span::default()
+            )))
+            .name(),
+            "literal expression"
+        );
+        assert_eq!(SelectItem::STAR(Span::default()).name(), "STAR");
+        assert_eq!(
+            SelectItem::QUALIFIEDSTAR(NameExpr::new(""), Span::default()).name(),
+            "QUALIFIED STAR"
+        );
     }
 
     #[test]
@@ -82,7 +166,7 @@ mod tests {
         );
         assert!(matches!(
             SelectItem::parse(&source, 0),
-            Err(err) if err.cause == "missing select item"
+            Err(err) if err.cause == "missing expression"
         ));
         // contain from item -> name expression
         source = SyntaxPattern::new(
@@ -91,13 +175,67 @@ mod tests {
         );
         assert!(matches!(
             SelectItem::parse(&source, 0),
-            Ok((item, index)) if matches!(&item, SelectItem::NAME(expr) if expr.value == "4dfa" && index == 1
+            Ok((item, index)) if matches!(&item, SelectItem::EXPR(Expr::NAME(expr)) if eq_ignore_span(expr, &NameExpr::new("4dfa")) && index == 1
         )));
+        // contain select item -> literal expression
+        source = SyntaxPattern::new(
+            "'7c'",
+            vec![Lexer::STRING(LexerMatch::new_full_match("'7c'"))],
+        );
+        assert!(matches!(
+            SelectItem::parse(&source, 0),
+            Ok((item, index)) if eq_ignore_span(&item, &SelectItem::EXPR(Expr::LITERAL(LiteralExpr::STRING("7c".into(), Span::default())))) && index == 1
+        ));
         // contain from item -> name expression
         source = SyntaxPattern::new("*", vec![Lexer::STAR(LexerMatch::new_full_match("*"))]);
         assert!(matches!(
             SelectItem::parse(&source, 0),
-            Ok((item, index)) if matches!(item, SelectItem::STAR) && index == 1
+            Ok((item, index)) if matches!(item, SelectItem::STAR(span) if span.range() == (0..1)) && index == 1
+        ));
+        // contain select item -> qualified name expression
+        let text = "t1.c1";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectItem::parse(&source, 0),
+            Ok((item, index)) if eq_ignore_span(&item, &SelectItem::EXPR(Expr::NAME(NameExpr::new_parts(vec![
+                IdentExpr::new("t1"),
+                IdentExpr::new("c1"),
+            ])))) && index == 3
+        ));
+        // contain select item -> qualified star
+        let text = "s1.t1.*";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectItem::parse(&source, 0),
+            Ok((item, index)) if eq_ignore_span(&item, &SelectItem::QUALIFIEDSTAR(NameExpr::new_parts(vec![
+                IdentExpr::new("s1"),
+                IdentExpr::new("t1"),
+            ]), Span::default())) && item.span().range() == (0..7) && index == 5
+        ));
+        // contain select item -> aggregate
+        let text = "count(*) AS n";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectItem::parse(&source, 0),
+            Ok((item, index)) if eq_ignore_span(&item, &SelectItem::EXPR(Expr::AGGREGATE(AggregateExpr::new_count_star()))) && index == 4
+        ));
+        // a column named like an aggregate function
+        let text = "count, max";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectItem::parse(&source, 0),
+            Ok((item, index)) if eq_ignore_span(&item, &SelectItem::EXPR(Expr::NAME(NameExpr::new("count")))) && index == 1
+        ));
+        // a star after an operand multiplies
+        let text = "t1.c1 * 2, *";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            SelectItem::parse(&source, 0),
+            Ok((item, index)) if eq_ignore_span(&item, &SelectItem::EXPR(Expr::BINARY(BinaryExpr::new(
+                Expr::NAME(NameExpr::new_parts(vec![IdentExpr::new("t1"), IdentExpr::new("c1")])),
+                BinaryOp::MUL,
+                Expr::LITERAL(LiteralExpr::INTEGER("2".into(), Span::default())),
+            )))) && index == 5
         ));
     }
 }
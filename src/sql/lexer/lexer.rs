@@ -3,68 +3,425 @@ use rsdb::{Is, NamedEnum};
 use super::mat::LexerMatch;
 
 /// The SQL lexer
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Lexer {
-    SELECT(LexerMatch),
-    FROM(LexerMatch),
-    ALIAS(LexerMatch),
-    STAR(LexerMatch),
-    COMMA(LexerMatch),
-    WHITESPACE(LexerMatch),
-    NAME(LexerMatch),
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lexer<'a> {
+    SELECT(LexerMatch<'a>),
+    FROM(LexerMatch<'a>),
+    WHERE(LexerMatch<'a>),
+    ALIAS(LexerMatch<'a>),
+    AND(LexerMatch<'a>),
+    OR(LexerMatch<'a>),
+    NOT(LexerMatch<'a>),
+    IS(LexerMatch<'a>),
+    NULL(LexerMatch<'a>),
+    BETWEEN(LexerMatch<'a>),
+    IN(LexerMatch<'a>),
+    ORDER(LexerMatch<'a>),
+    BY(LexerMatch<'a>),
+    ASC(LexerMatch<'a>),
+    DESC(LexerMatch<'a>),
+    NULLS(LexerMatch<'a>),
+    FIRST(LexerMatch<'a>),
+    LAST(LexerMatch<'a>),
+    LIMIT(LexerMatch<'a>),
+    OFFSET(LexerMatch<'a>),
+    FETCH(LexerMatch<'a>),
+    NEXT(LexerMatch<'a>),
+    ROW(LexerMatch<'a>),
+    ROWS(LexerMatch<'a>),
+    ONLY(LexerMatch<'a>),
+    WITH(LexerMatch<'a>),
+    TIES(LexerMatch<'a>),
+    GROUP(LexerMatch<'a>),
+    HAVING(LexerMatch<'a>),
+    DISTINCT(LexerMatch<'a>),
+    JOIN(LexerMatch<'a>),
+    INNER(LexerMatch<'a>),
+    LEFT(LexerMatch<'a>),
+    RIGHT(LexerMatch<'a>),
+    FULL(LexerMatch<'a>),
+    OUTER(LexerMatch<'a>),
+    CROSS(LexerMatch<'a>),
+    NATURAL(LexerMatch<'a>),
+    ON(LexerMatch<'a>),
+    USING(LexerMatch<'a>),
+    EXISTS(LexerMatch<'a>),
+    ANY(LexerMatch<'a>),
+    ALL(LexerMatch<'a>),
+    RECURSIVE(LexerMatch<'a>),
+    MATERIALIZED(LexerMatch<'a>),
+    UNION(LexerMatch<'a>),
+    INTERSECT(LexerMatch<'a>),
+    EXCEPT(LexerMatch<'a>),
+    LIKE(LexerMatch<'a>),
+    ILIKE(LexerMatch<'a>),
+    SIMILAR(LexerMatch<'a>),
+    TO(LexerMatch<'a>),
+    EQ(LexerMatch<'a>),
+    NE(LexerMatch<'a>),
+    LT(LexerMatch<'a>),
+    LE(LexerMatch<'a>),
+    GT(LexerMatch<'a>),
+    GE(LexerMatch<'a>),
+    LPAREN(LexerMatch<'a>),
+    RPAREN(LexerMatch<'a>),
+    DOT(LexerMatch<'a>),
+    SEMICOLON(LexerMatch<'a>),
+    STAR(LexerMatch<'a>),
+    PLUS(LexerMatch<'a>),
+    MINUS(LexerMatch<'a>),
+    SLASH(LexerMatch<'a>),
+    PERCENT(LexerMatch<'a>),
+    CONCAT(LexerMatch<'a>),
+    COMMA(LexerMatch<'a>),
+    INTEGER(LexerMatch<'a>),
+    DECIMAL(LexerMatch<'a>),
+    SCIENTIFIC(LexerMatch<'a>),
+    HEX(LexerMatch<'a>),
+    STRING(LexerMatch<'a>),
+    ESTRING(LexerMatch<'a>),
+    COMMENT(LexerMatch<'a>),
+    WHITESPACE(LexerMatch<'a>),
+    PARAM(LexerMatch<'a>),
+    QUOTED(LexerMatch<'a>),
+    NAME(LexerMatch<'a>),
 }
 
-impl NamedEnum for Lexer {
+impl NamedEnum for Lexer<'_> {
     fn name(&self) -> &'static str {
         match self {
             Self::SELECT(_) => "SELECT",
             Self::FROM(_) => "FROM",
+            Self::WHERE(_) => "WHERE",
             Self::ALIAS(_) => "AS",
+            Self::AND(_) => "AND",
+            Self::OR(_) => "OR",
+            Self::NOT(_) => "NOT",
+            Self::IS(_) => "IS",
+            Self::NULL(_) => "NULL",
+            Self::BETWEEN(_) => "BETWEEN",
+            Self::IN(_) => "IN",
+            Self::ORDER(_) => "ORDER",
+            Self::BY(_) => "BY",
+            Self::ASC(_) => "ASC",
+            Self::DESC(_) => "DESC",
+            Self::NULLS(_) => "NULLS",
+            Self::FIRST(_) => "FIRST",
+            Self::LAST(_) => "LAST",
+            Self::LIMIT(_) => "LIMIT",
+            Self::OFFSET(_) => "OFFSET",
+            Self::FETCH(_) => "FETCH",
+            Self::NEXT(_) => "NEXT",
+            Self::ROW(_) => "ROW",
+            Self::ROWS(_) => "ROWS",
+            Self::ONLY(_) => "ONLY",
+            Self::WITH(_) => "WITH",
+            Self::TIES(_) => "TIES",
+            Self::GROUP(_) => "GROUP",
+            Self::HAVING(_) => "HAVING",
+            Self::DISTINCT(_) => "DISTINCT",
+            Self::JOIN(_) => "JOIN",
+            Self::INNER(_) => "INNER",
+            Self::LEFT(_) => "LEFT",
+            Self::RIGHT(_) => "RIGHT",
+            Self::FULL(_) => "FULL",
+            Self::OUTER(_) => "OUTER",
+            Self::CROSS(_) => "CROSS",
+            Self::NATURAL(_) => "NATURAL",
+            Self::ON(_) => "ON",
+            Self::USING(_) => "USING",
+            Self::EXISTS(_) => "EXISTS",
+            Self::ANY(_) => "ANY",
+            Self::ALL(_) => "ALL",
+            Self::RECURSIVE(_) => "RECURSIVE",
+            Self::MATERIALIZED(_) => "MATERIALIZED",
+            Self::UNION(_) => "UNION",
+            Self::INTERSECT(_) => "INTERSECT",
+            Self::EXCEPT(_) => "EXCEPT",
+            Self::LIKE(_) => "LIKE",
+            Self::ILIKE(_) => "ILIKE",
+            Self::SIMILAR(_) => "SIMILAR",
+            Self::TO(_) => "TO",
+            Self::EQ(_) => "EQ",
+            Self::NE(_) => "NE",
+            Self::LT(_) => "LT",
+            Self::LE(_) => "LE",
+            Self::GT(_) => "GT",
+            Self::GE(_) => "GE",
+            Self::LPAREN(_) => "LPAREN",
+            Self::RPAREN(_) => "RPAREN",
+            Self::DOT(_) => "DOT",
+            Self::SEMICOLON(_) => "SEMICOLON",
             Self::STAR(_) => "STAR",
+            Self::PLUS(_) => "PLUS",
+            Self::MINUS(_) => "MINUS",
+            Self::SLASH(_) => "SLASH",
+            Self::PERCENT(_) => "PERCENT",
+            Self::CONCAT(_) => "CONCAT",
             Self::COMMA(_) => "COMMA",
+            Self::INTEGER(_) => "INTEGER",
+            Self::DECIMAL(_) => "DECIMAL",
+            Self::SCIENTIFIC(_) => "SCIENTIFIC",
+            Self::HEX(_) => "HEX",
+            Self::STRING(_) => "STRING",
+            Self::ESTRING(_) => "ESTRING",
+            Self::COMMENT(_) => "COMMENT",
             Self::WHITESPACE(_) => "WHITESPACE",
+            Self::PARAM(_) => "PARAM",
+            Self::QUOTED(_) => "QUOTED",
             Self::NAME(_) => "NAME",
         }
     }
 }
 
-impl Is for Lexer {
+impl Is for Lexer<'_> {
     fn is(&self, other: &Self) -> bool {
         match self {
             Self::SELECT(_) => matches!(other, Self::SELECT(_)),
             Self::FROM(_) => matches!(other, Self::FROM(_)),
+            Self::WHERE(_) => matches!(other, Self::WHERE(_)),
             Self::ALIAS(_) => matches!(other, Self::ALIAS(_)),
+            Self::AND(_) => matches!(other, Self::AND(_)),
+            Self::OR(_) => matches!(other, Self::OR(_)),
+            Self::NOT(_) => matches!(other, Self::NOT(_)),
+            Self::IS(_) => matches!(other, Self::IS(_)),
+            Self::NULL(_) => matches!(other, Self::NULL(_)),
+            Self::BETWEEN(_) => matches!(other, Self::BETWEEN(_)),
+            Self::IN(_) => matches!(other, Self::IN(_)),
+            Self::ORDER(_) => matches!(other, Self::ORDER(_)),
+            Self::BY(_) => matches!(other, Self::BY(_)),
+            Self::ASC(_) => matches!(other, Self::ASC(_)),
+            Self::DESC(_) => matches!(other, Self::DESC(_)),
+            Self::NULLS(_) => matches!(other, Self::NULLS(_)),
+            Self::FIRST(_) => matches!(other, Self::FIRST(_)),
+            Self::LAST(_) => matches!(other, Self::LAST(_)),
+            Self::LIMIT(_) => matches!(other, Self::LIMIT(_)),
+            Self::OFFSET(_) => matches!(other, Self::OFFSET(_)),
+            Self::FETCH(_) => matches!(other, Self::FETCH(_)),
+            Self::NEXT(_) => matches!(other, Self::NEXT(_)),
+            Self::ROW(_) => matches!(other, Self::ROW(_)),
+            Self::ROWS(_) => matches!(other, Self::ROWS(_)),
+            Self::ONLY(_) => matches!(other, Self::ONLY(_)),
+            Self::WITH(_) => matches!(other, Self::WITH(_)),
+            Self::TIES(_) => matches!(other, Self::TIES(_)),
+            Self::GROUP(_) => matches!(other, Self::GROUP(_)),
+            Self::HAVING(_) => matches!(other, Self::HAVING(_)),
+            Self::DISTINCT(_) => matches!(other, Self::DISTINCT(_)),
+            Self::JOIN(_) => matches!(other, Self::JOIN(_)),
+            Self::INNER(_) => matches!(other, Self::INNER(_)),
+            Self::LEFT(_) => matches!(other, Self::LEFT(_)),
+            Self::RIGHT(_) => matches!(other, Self::RIGHT(_)),
+            Self::FULL(_) => matches!(other, Self::FULL(_)),
+            Self::OUTER(_) => matches!(other, Self::OUTER(_)),
+            Self::CROSS(_) => matches!(other, Self::CROSS(_)),
+            Self::NATURAL(_) => matches!(other, Self::NATURAL(_)),
+            Self::ON(_) => matches!(other, Self::ON(_)),
+            Self::USING(_) => matches!(other, Self::USING(_)),
+            Self::EXISTS(_) => matches!(other, Self::EXISTS(_)),
+            Self::ANY(_) => matches!(other, Self::ANY(_)),
+            Self::ALL(_) => matches!(other, Self::ALL(_)),
+            Self::RECURSIVE(_) => matches!(other, Self::RECURSIVE(_)),
+            Self::MATERIALIZED(_) => matches!(other, Self::MATERIALIZED(_)),
+            Self::UNION(_) => matches!(other, Self::UNION(_)),
+            Self::INTERSECT(_) => matches!(other, Self::INTERSECT(_)),
+            Self::EXCEPT(_) => matches!(other, Self::EXCEPT(_)),
+            Self::LIKE(_) => matches!(other, Self::LIKE(_)),
+            Self::ILIKE(_) => matches!(other, Self::ILIKE(_)),
+            Self::SIMILAR(_) => matches!(other, Self::SIMILAR(_)),
+            Self::TO(_) => matches!(other, Self::TO(_)),
+            Self::EQ(_) => matches!(other, Self::EQ(_)),
+            Self::NE(_) => matches!(other, Self::NE(_)),
+            Self::LT(_) => matches!(other, Self::LT(_)),
+            Self::LE(_) => matches!(other, Self::LE(_)),
+            Self::GT(_) => matches!(other, Self::GT(_)),
+            Self::GE(_) => matches!(other, Self::GE(_)),
+            Self::LPAREN(_) => matches!(other, Self::LPAREN(_)),
+            Self::RPAREN(_) => matches!(other, Self::RPAREN(_)),
+            Self::DOT(_) => matches!(other, Self::DOT(_)),
+            Self::SEMICOLON(_) => matches!(other, Self::SEMICOLON(_)),
             Self::STAR(_) => matches!(other, Self::STAR(_)),
+            Self::PLUS(_) => matches!(other, Self::PLUS(_)),
+            Self::MINUS(_) => matches!(other, Self::MINUS(_)),
+            Self::SLASH(_) => matches!(other, Self::SLASH(_)),
+            Self::PERCENT(_) => matches!(other, Self::PERCENT(_)),
+            Self::CONCAT(_) => matches!(other, Self::CONCAT(_)),
             Self::COMMA(_) => matches!(other, Self::COMMA(_)),
+            Self::INTEGER(_) => matches!(other, Self::INTEGER(_)),
+            Self::DECIMAL(_) => matches!(other, Self::DECIMAL(_)),
+            Self::SCIENTIFIC(_) => matches!(other, Self::SCIENTIFIC(_)),
+            Self::HEX(_) => matches!(other, Self::HEX(_)),
+            Self::STRING(_) => matches!(other, Self::STRING(_)),
+            Self::ESTRING(_) => matches!(other, Self::ESTRING(_)),
+            Self::COMMENT(_) => matches!(other, Self::COMMENT(_)),
             Self::WHITESPACE(_) => matches!(other, Self::WHITESPACE(_)),
+            Self::PARAM(_) => matches!(other, Self::PARAM(_)),
+            Self::QUOTED(_) => matches!(other, Self::QUOTED(_)),
             Self::NAME(_) => matches!(other, Self::NAME(_)),
         }
     }
 }
 
-impl Lexer {
-    pub fn is_value_ignores(&self) -> bool {
+impl<'a> Lexer<'a> {
+    pub fn is_clause(&self) -> bool {
         matches!(
             self,
-            Self::SELECT(_) | Self::FROM(_) | Self::STAR(_) | Self::COMMA(_) | Self::WHITESPACE(_)
+            Self::SELECT(_)
+                | Self::FROM(_)
+                | Self::WHERE(_)
+                | Self::GROUP(_)
+                | Self::HAVING(_)
+                | Self::UNION(_)
+                | Self::INTERSECT(_)
+                | Self::EXCEPT(_)
+                | Self::ORDER(_)
+                | Self::LIMIT(_)
+                | Self::OFFSET(_)
+                | Self::FETCH(_)
         )
     }
 
-    pub fn is_clause(&self) -> bool {
-        matches!(self, Self::SELECT(_) | Self::FROM(_))
+    /// Keywords which may be used as identifiers too, e.g. a column named `last`
+    ///
+    /// `ANY`, `SIMILAR` and `TO` are reserved in standard SQL, `ILIKE` is not a standard keyword.
+    pub fn is_non_reserved(&self) -> bool {
+        matches!(
+            self,
+            Self::ASC(_)
+                | Self::DESC(_)
+                | Self::NULLS(_)
+                | Self::FIRST(_)
+                | Self::LAST(_)
+                | Self::NEXT(_)
+                | Self::ROW(_)
+                | Self::ROWS(_)
+                | Self::ONLY(_)
+                | Self::TIES(_)
+                | Self::RECURSIVE(_)
+                | Self::MATERIALIZED(_)
+                | Self::ILIKE(_)
+        )
     }
 
-    pub fn value(&self) -> LexerMatch {
+    /// Names, quoted names and non reserved keywords
+    pub fn is_ident(&self) -> bool {
+        matches!(self, Self::NAME(_) | Self::QUOTED(_)) || self.is_non_reserved()
+    }
+
+    /// The lexer as named in `expected ..., got ...` errors, keywords upper case,
+    /// punctuation quoted and the other lexers by their kind in lower case
+    pub fn label(&self) -> &'static str {
         match self {
+            Self::EQ(_) => "'='",
+            Self::NE(_) => "'<>'",
+            Self::LT(_) => "'<'",
+            Self::LE(_) => "'<='",
+            Self::GT(_) => "'>'",
+            Self::GE(_) => "'>='",
+            Self::LPAREN(_) => "'('",
+            Self::RPAREN(_) => "')'",
+            Self::DOT(_) => "'.'",
+            Self::SEMICOLON(_) => "';'",
+            Self::STAR(_) => "'*'",
+            Self::PLUS(_) => "'+'",
+            Self::MINUS(_) => "'-'",
+            Self::SLASH(_) => "'/'",
+            Self::PERCENT(_) => "'%'",
+            Self::CONCAT(_) => "'||'",
+            Self::COMMA(_) => "','",
+            Self::INTEGER(_) => "integer",
+            Self::DECIMAL(_) | Self::SCIENTIFIC(_) | Self::HEX(_) => "number",
+            Self::STRING(_) | Self::ESTRING(_) => "string",
+            Self::COMMENT(_) => "comment",
+            Self::WHITESPACE(_) => "whitespace",
+            Self::PARAM(_) => "parameter",
+            Self::QUOTED(_) | Self::NAME(_) => "identifier",
+            _ => self.name(),
+        }
+    }
+
+    pub fn value(&self) -> LexerMatch<'a> {
+        match *self {
             Self::SELECT(value) => value,
             Self::FROM(value) => value,
+            Self::WHERE(value) => value,
             Self::ALIAS(value) => value,
+            Self::AND(value) => value,
+            Self::OR(value) => value,
+            Self::NOT(value) => value,
+            Self::IS(value) => value,
+            Self::NULL(value) => value,
+            Self::BETWEEN(value) => value,
+            Self::IN(value) => value,
+            Self::ORDER(value) => value,
+            Self::BY(value) => value,
+            Self::ASC(value) => value,
+            Self::DESC(value) => value,
+            Self::NULLS(value) => value,
+            Self::FIRST(value) => value,
+            Self::LAST(value) => value,
+            Self::LIMIT(value) => value,
+            Self::OFFSET(value) => value,
+            Self::FETCH(value) => value,
+            Self::NEXT(value) => value,
+            Self::ROW(value) => value,
+            Self::ROWS(value) => value,
+            Self::ONLY(value) => value,
+            Self::WITH(value) => value,
+            Self::TIES(value) => value,
+            Self::GROUP(value) => value,
+            Self::HAVING(value) => value,
+            Self::DISTINCT(value) => value,
+            Self::JOIN(value) => value,
+            Self::INNER(value) => value,
+            Self::LEFT(value) => value,
+            Self::RIGHT(value) => value,
+            Self::FULL(value) => value,
+            Self::OUTER(value) => value,
+            Self::CROSS(value) => value,
+            Self::NATURAL(value) => value,
+            Self::ON(value) => value,
+            Self::USING(value) => value,
+            Self::EXISTS(value) => value,
+            Self::ANY(value) => value,
+            Self::ALL(value) => value,
+            Self::RECURSIVE(value) => value,
+            Self::MATERIALIZED(value) => value,
+            Self::UNION(value) => value,
+            Self::INTERSECT(value) => value,
+            Self::EXCEPT(value) => value,
+            Self::LIKE(value) => value,
+            Self::ILIKE(value) => value,
+            Self::SIMILAR(value) => value,
+            Self::TO(value) => value,
+            Self::EQ(value) => value,
+            Self::NE(value) => value,
+            Self::LT(value) => value,
+            Self::LE(value) => value,
+            Self::GT(value) => value,
+            Self::GE(value) => value,
+            Self::LPAREN(value) => value,
+            Self::RPAREN(value) => value,
+            Self::DOT(value) => value,
+            Self::SEMICOLON(value) => value,
             Self::STAR(value) => value,
+            Self::PLUS(value) => value,
+            Self::MINUS(value) => value,
+            Self::SLASH(value) => value,
+            Self::PERCENT(value) => value,
+            Self::CONCAT(value) => value,
             Self::COMMA(value) => value,
+            Self::INTEGER(value) => value,
+            Self::DECIMAL(value) => value,
+            Self::SCIENTIFIC(value) => value,
+            Self::HEX(value) => value,
+            Self::STRING(value) => value,
+            Self::ESTRING(value) => value,
+            Self::COMMENT(value) => value,
             Self::WHITESPACE(value) => value,
+            Self::PARAM(value) => value,
+            Self::QUOTED(value) => value,
             Self::NAME(value) => value,
         }
-        .clone()
     }
 }
 
@@ -72,42 +429,118 @@ impl Lexer {
 mod tests {
     use rsdb::NamedEnum;
 
-    use crate::sql::lexer::mat::LexerMatch;
+    use crate::sql::lexer::{mat::LexerMatch, pattern::LexerPattern};
 
     use super::Lexer;
 
-    fn new_lexer(name: &str, value: &str) -> Lexer {
-        match name {
-            "SELECT" => Lexer::SELECT(LexerMatch::new_full_match(value)),
-            "FROM" => Lexer::FROM(LexerMatch::new_full_match(value)),
-            "AS" => Lexer::ALIAS(LexerMatch::new_full_match(value)),
-            "STAR" => Lexer::STAR(LexerMatch::new_full_match(value)),
-            "COMMA" => Lexer::COMMA(LexerMatch::new_full_match(value)),
-            "WHITESPACE" => Lexer::WHITESPACE(LexerMatch::new_full_match(value)),
-            "NAME" => Lexer::NAME(LexerMatch::new_full_match(value)),
-            _ => panic!("err lexer"),
-        }
-    }
-
     #[test]
-    fn it_name() {
-        assert_eq!(new_lexer("SELECT", "").name(), "SELECT");
-        assert_eq!(new_lexer("FROM", "").name(), "FROM");
-        assert_eq!(new_lexer("AS", "").name(), "AS");
-        assert_eq!(new_lexer("STAR", "").name(), "STAR");
-        assert_eq!(new_lexer("COMMA", "").name(), "COMMA");
-        assert_eq!(new_lexer("WHITESPACE", "").name(), "WHITESPACE");
-        assert_eq!(new_lexer("NAME", "").name(), "NAME");
-    }
-
-    #[test]
-    fn it_value() {
-        assert_eq!(new_lexer("SELECT", "cc45").value().as_str(), "cc45");
-        assert_eq!(new_lexer("FROM", "d733").value().as_str(), "d733");
-        assert_eq!(new_lexer("AS", "46c6").value().as_str(), "46c6");
-        assert_eq!(new_lexer("STAR", "debb").value().as_str(), "debb");
-        assert_eq!(new_lexer("COMMA", "41ce").value().as_str(), "41ce");
-        assert_eq!(new_lexer("WHITESPACE", "b734").value().as_str(), "b734");
-        assert_eq!(new_lexer("NAME", "ee4f").value().as_str(), "ee4f");
+    fn it_lexers() {
+        // text, name, label
+        let lexers = [
+            ("select", "SELECT", "SELECT"),
+            ("from", "FROM", "FROM"),
+            ("where", "WHERE", "WHERE"),
+            ("as", "AS", "AS"),
+            ("and", "AND", "AND"),
+            ("or", "OR", "OR"),
+            ("not", "NOT", "NOT"),
+            ("is", "IS", "IS"),
+            ("null", "NULL", "NULL"),
+            ("between", "BETWEEN", "BETWEEN"),
+            ("in", "IN", "IN"),
+            ("order", "ORDER", "ORDER"),
+            ("by", "BY", "BY"),
+            ("asc", "ASC", "ASC"),
+            ("desc", "DESC", "DESC"),
+            ("nulls", "NULLS", "NULLS"),
+            ("first", "FIRST", "FIRST"),
+            ("last", "LAST", "LAST"),
+            ("limit", "LIMIT", "LIMIT"),
+            ("offset", "OFFSET", "OFFSET"),
+            ("fetch", "FETCH", "FETCH"),
+            ("next", "NEXT", "NEXT"),
+            ("row", "ROW", "ROW"),
+            ("rows", "ROWS", "ROWS"),
+            ("only", "ONLY", "ONLY"),
+            ("with", "WITH", "WITH"),
+            ("ties", "TIES", "TIES"),
+            ("group", "GROUP", "GROUP"),
+            ("having", "HAVING", "HAVING"),
+            ("distinct", "DISTINCT", "DISTINCT"),
+            ("join", "JOIN", "JOIN"),
+            ("inner", "INNER", "INNER"),
+            ("left", "LEFT", "LEFT"),
+            ("right", "RIGHT", "RIGHT"),
+            ("full", "FULL", "FULL"),
+            ("outer", "OUTER", "OUTER"),
+            ("cross", "CROSS", "CROSS"),
+            ("natural", "NATURAL", "NATURAL"),
+            ("on", "ON", "ON"),
+            ("using", "USING", "USING"),
+            ("exists", "EXISTS", "EXISTS"),
+            ("any", "ANY", "ANY"),
+            ("all", "ALL", "ALL"),
+            ("recursive", "RECURSIVE", "RECURSIVE"),
+            ("materialized", "MATERIALIZED", "MATERIALIZED"),
+            ("union", "UNION", "UNION"),
+            ("intersect", "INTERSECT", "INTERSECT"),
+            ("except", "EXCEPT", "EXCEPT"),
+            ("like", "LIKE", "LIKE"),
+            ("ilike", "ILIKE", "ILIKE"),
+            ("similar", "SIMILAR", "SIMILAR"),
+            ("to", "TO", "TO"),
+            ("=", "EQ", "'='"),
+            ("<>", "NE", "'<>'"),
+            ("!=", "NE", "'<>'"),
+            ("<", "LT", "'<'"),
+            ("<=", "LE", "'<='"),
+            (">", "GT", "'>'"),
+            (">=", "GE", "'>='"),
+            ("(", "LPAREN", "'('"),
+            (")", "RPAREN", "')'"),
+            (".", "DOT", "'.'"),
+            (";", "SEMICOLON", "';'"),
+            ("*", "STAR", "'*'"),
+            ("+", "PLUS", "'+'"),
+            ("-", "MINUS", "'-'"),
+            ("/", "SLASH", "'/'"),
+            ("%", "PERCENT", "'%'"),
+            ("||", "CONCAT", "'||'"),
+            (",", "COMMA", "','"),
+            ("42", "INTEGER", "integer"),
+            ("4.2", "DECIMAL", "number"),
+            ("4.2e-1", "SCIENTIFIC", "number"),
+            ("0x2A", "HEX", "number"),
+            ("'a''b'", "STRING", "string"),
+            (r"E'a\tb'", "ESTRING", "string"),
+            ("$1", "PARAM", "parameter"),
+            ("?", "PARAM", "parameter"),
+            (":name", "PARAM", "parameter"),
+            (r#""Order Details""#, "QUOTED", "identifier"),
+            ("`us``er`", "QUOTED", "identifier"),
+            ("t_1", "NAME", "identifier"),
+        ];
+        for (text, name, label) in lexers {
+            let lexers = LexerPattern::new(text).matches().unwrap();
+            assert_eq!(lexers.len(), 1, "{}", text);
+            assert_eq!(lexers[0].name(), name, "{}", text);
+            assert_eq!(lexers[0].label(), label, "{}", text);
+            assert_eq!(lexers[0].value().as_str(), text, "{}", text);
+        }
+        // whitespace and comments are skipped
+        for text in [" \r\n\t", "-- a", "/* a /* b */ */"] {
+            assert!(
+                LexerPattern::new(text).matches().unwrap().is_empty(),
+                "{}",
+                text
+            );
+        }
+        let whitespace = Lexer::WHITESPACE(LexerMatch::new_full_match(" "));
+        assert_eq!(
+            (whitespace.name(), whitespace.label()),
+            ("WHITESPACE", "whitespace")
+        );
+        let comment = Lexer::COMMENT(LexerMatch::new_full_match("-- a"));
+        assert_eq!((comment.name(), comment.label()), ("COMMENT", "comment"));
     }
 }
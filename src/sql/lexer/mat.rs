@@ -1,33 +1,25 @@
 use std::ops::Range;
 
-use regex::Match;
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct LexerMatch {
-    text: String,
+/// A span of the source text, it borrows the text instead of copying it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LexerMatch<'a> {
+    text: &'a str,
     start: usize,
     end: usize,
 }
 
-impl LexerMatch {
-    pub fn new(text: &str, start: usize, end: usize) -> Self {
-        Self {
-            text: text.to_owned(),
-            start,
-            end,
-        }
-    }
-
-    pub fn new_match(text: &str, mat: &Match) -> Self {
-        LexerMatch::new(text, mat.start(), mat.end())
+impl<'a> LexerMatch<'a> {
+    pub fn new(text: &'a str, start: usize, end: usize) -> Self {
+        Self { text, start, end }
     }
 
-    pub fn new_full_match(text: &str) -> Self {
+    pub fn new_full_match(text: &'a str) -> Self {
         LexerMatch::new(text, 0, text.len())
     }
 
-    pub fn new_eof(text: &str) -> Self {
-        let start = if text.len() > 0 { text.len() - 1 } else { 0 };
+    pub fn new_eof(text: &'a str) -> Self {
+        // the last char, which may be longer than a byte
+        let start = text.char_indices().last().map_or(0, |(i, _)| i);
         LexerMatch::new(text, start, text.len())
     }
 
@@ -43,11 +35,11 @@ impl LexerMatch {
         self.start..self.end
     }
 
-    pub fn text(&self) -> &str {
-        self.text.as_str()
+    pub fn text(&self) -> &'a str {
+        self.text
     }
 
-    pub fn as_str(&self) -> &str {
+    pub fn as_str(&self) -> &'a str {
         &self.text[self.range()]
     }
 }
@@ -1,3 +1,4 @@
-pub mod mat;
+#[allow(clippy::module_inception)]
 pub mod lexer;
+pub mod mat;
 pub mod pattern;
@@ -1,131 +1,458 @@
-use regex::Regex;
+use std::sync::OnceLock;
+
+use regex::{Regex, RegexSet};
 
 use crate::sql::err::SyntaxError;
 
 use super::{lexer::Lexer, mat::LexerMatch};
 
-#[derive(Debug, Clone)]
-pub struct LexerPattern {
-    text: String,
+#[derive(Debug, Clone, Copy)]
+pub struct LexerPattern<'a> {
+    text: &'a str,
 }
 
-impl LexerPattern {
+impl<'a> LexerPattern<'a> {
     const PATTERN_SELECT: &'static str = "^(?i)SELECT";
     const PATTERN_FROM: &'static str = "^(?i)FROM";
+    const PATTERN_WHERE: &'static str = "^(?i)WHERE";
     const PATTERN_ALIAS: &'static str = "^(?i)AS";
+    const PATTERN_AND: &'static str = "^(?i)AND";
+    const PATTERN_OR: &'static str = "^(?i)OR";
+    const PATTERN_NOT: &'static str = "^(?i)NOT";
+    const PATTERN_IS: &'static str = "^(?i)IS";
+    const PATTERN_NULL: &'static str = "^(?i)NULL";
+    const PATTERN_BETWEEN: &'static str = "^(?i)BETWEEN";
+    const PATTERN_IN: &'static str = "^(?i)IN";
+    const PATTERN_LT: &'static str = "^<";
+    const PATTERN_GT: &'static str = "^>";
+    const PATTERN_ORDER: &'static str = "^(?i)ORDER";
+    const PATTERN_BY: &'static str = "^(?i)BY";
+    const PATTERN_ASC: &'static str = "^(?i)ASC";
+    const PATTERN_DESC: &'static str = "^(?i)DESC";
+    const PATTERN_NULLS: &'static str = "^(?i)NULLS";
+    const PATTERN_FIRST: &'static str = "^(?i)FIRST";
+    const PATTERN_LAST: &'static str = "^(?i)LAST";
+    const PATTERN_LIMIT: &'static str = "^(?i)LIMIT";
+    const PATTERN_OFFSET: &'static str = "^(?i)OFFSET";
+    const PATTERN_FETCH: &'static str = "^(?i)FETCH";
+    const PATTERN_NEXT: &'static str = "^(?i)NEXT";
+    const PATTERN_ROW: &'static str = "^(?i)ROW";
+    const PATTERN_ROWS: &'static str = "^(?i)ROWS";
+    const PATTERN_ONLY: &'static str = "^(?i)ONLY";
+    const PATTERN_WITH: &'static str = "^(?i)WITH";
+    const PATTERN_TIES: &'static str = "^(?i)TIES";
+    const PATTERN_GROUP: &'static str = "^(?i)GROUP";
+    const PATTERN_HAVING: &'static str = "^(?i)HAVING";
+    const PATTERN_DISTINCT: &'static str = "^(?i)DISTINCT";
+    const PATTERN_JOIN: &'static str = "^(?i)JOIN";
+    const PATTERN_INNER: &'static str = "^(?i)INNER";
+    const PATTERN_LEFT: &'static str = "^(?i)LEFT";
+    const PATTERN_RIGHT: &'static str = "^(?i)RIGHT";
+    const PATTERN_FULL: &'static str = "^(?i)FULL";
+    const PATTERN_OUTER: &'static str = "^(?i)OUTER";
+    const PATTERN_CROSS: &'static str = "^(?i)CROSS";
+    const PATTERN_NATURAL: &'static str = "^(?i)NATURAL";
+    const PATTERN_ON: &'static str = "^(?i)ON";
+    const PATTERN_USING: &'static str = "^(?i)USING";
+    const PATTERN_EXISTS: &'static str = "^(?i)EXISTS";
+    const PATTERN_ANY: &'static str = "^(?i)ANY";
+    const PATTERN_ALL: &'static str = "^(?i)ALL";
+    const PATTERN_RECURSIVE: &'static str = "^(?i)RECURSIVE";
+    const PATTERN_MATERIALIZED: &'static str = "^(?i)MATERIALIZED";
+    const PATTERN_UNION: &'static str = "^(?i)UNION";
+    const PATTERN_INTERSECT: &'static str = "^(?i)INTERSECT";
+    const PATTERN_EXCEPT: &'static str = "^(?i)EXCEPT";
+    const PATTERN_LIKE: &'static str = "^(?i)LIKE";
+    const PATTERN_ILIKE: &'static str = "^(?i)ILIKE";
+    const PATTERN_SIMILAR: &'static str = "^(?i)SIMILAR";
+    const PATTERN_TO: &'static str = "^(?i)TO";
+    const PATTERN_EQ: &'static str = "^=";
+    const PATTERN_LE: &'static str = "^<=";
+    const PATTERN_GE: &'static str = "^>=";
+    const PATTERN_NE: &'static str = "^(<>|!=)";
+    const PATTERN_LPAREN: &'static str = r"^\(";
+    const PATTERN_RPAREN: &'static str = r"^\)";
+    const PATTERN_DOT: &'static str = r"^\.";
+    const PATTERN_SEMICOLON: &'static str = "^;";
     const PATTERN_STAR: &'static str = r"^\*";
+    const PATTERN_PLUS: &'static str = r"^\+";
+    const PATTERN_MINUS: &'static str = "^-";
+    const PATTERN_SLASH: &'static str = "^/";
+    const PATTERN_PERCENT: &'static str = "^%";
+    const PATTERN_CONCAT: &'static str = r"^\|\|";
     const PATTERN_COMMA: &'static str = "^,";
+    const PATTERN_INTEGER: &'static str = r"^[0-9]+";
+    const PATTERN_DECIMAL: &'static str = r"^([0-9]+\.[0-9]*|\.[0-9]+)";
+    const PATTERN_SCIENTIFIC: &'static str = r"^([0-9]+(\.[0-9]*)?|\.[0-9]+)[eE][+-]?[0-9]+";
+    const PATTERN_HEX: &'static str = r"^0[xX][0-9A-Fa-f]+";
+    const PATTERN_STRING: &'static str = r"^'([^']|'')*'";
+    const PATTERN_ESTRING: &'static str = r"^[eE]'([^'\\]|''|\\.)*'";
+    const PATTERN_COMMENT: &'static str = r"^--[^\r\n]*";
     const PATTERN_WHITESPACE: &'static str = r"^[\s]+";
-    const PATTERN_NAME: &'static str = r"^[A-Za-z][\w]*";
+    const PATTERN_PARAM: &'static str = r"^(\?|\$[0-9]+|:[A-Za-z_][\w]*)";
+    const PATTERN_QUOTED: &'static str = r#"^("([^"]|"")+"|`([^`]|``)+`)"#;
+    const PATTERN_NAME: &'static str = r"^[A-Za-z_][\w]*";
 
-    const PATTERNS: [&'static str; 7] = [
+    const PATTERNS: [&'static str; 80] = [
         Self::PATTERN_SELECT,
         Self::PATTERN_FROM,
+        Self::PATTERN_WHERE,
         Self::PATTERN_ALIAS,
+        Self::PATTERN_AND,
+        Self::PATTERN_OR,
+        Self::PATTERN_NOT,
+        Self::PATTERN_IS,
+        Self::PATTERN_NULL,
+        Self::PATTERN_BETWEEN,
+        Self::PATTERN_IN,
+        Self::PATTERN_LT,
+        Self::PATTERN_GT,
+        Self::PATTERN_ORDER,
+        Self::PATTERN_BY,
+        Self::PATTERN_ASC,
+        Self::PATTERN_DESC,
+        Self::PATTERN_NULLS,
+        Self::PATTERN_FIRST,
+        Self::PATTERN_LAST,
+        Self::PATTERN_LIMIT,
+        Self::PATTERN_OFFSET,
+        Self::PATTERN_FETCH,
+        Self::PATTERN_NEXT,
+        Self::PATTERN_ROW,
+        Self::PATTERN_ROWS,
+        Self::PATTERN_ONLY,
+        Self::PATTERN_WITH,
+        Self::PATTERN_TIES,
+        Self::PATTERN_GROUP,
+        Self::PATTERN_HAVING,
+        Self::PATTERN_DISTINCT,
+        Self::PATTERN_JOIN,
+        Self::PATTERN_INNER,
+        Self::PATTERN_LEFT,
+        Self::PATTERN_RIGHT,
+        Self::PATTERN_FULL,
+        Self::PATTERN_OUTER,
+        Self::PATTERN_CROSS,
+        Self::PATTERN_NATURAL,
+        Self::PATTERN_ON,
+        Self::PATTERN_USING,
+        Self::PATTERN_EXISTS,
+        Self::PATTERN_ANY,
+        Self::PATTERN_ALL,
+        Self::PATTERN_RECURSIVE,
+        Self::PATTERN_MATERIALIZED,
+        Self::PATTERN_UNION,
+        Self::PATTERN_INTERSECT,
+        Self::PATTERN_EXCEPT,
+        Self::PATTERN_LIKE,
+        Self::PATTERN_ILIKE,
+        Self::PATTERN_SIMILAR,
+        Self::PATTERN_TO,
+        Self::PATTERN_EQ,
+        Self::PATTERN_LE,
+        Self::PATTERN_GE,
+        Self::PATTERN_NE,
+        Self::PATTERN_LPAREN,
+        Self::PATTERN_RPAREN,
+        Self::PATTERN_DOT,
+        Self::PATTERN_SEMICOLON,
         Self::PATTERN_STAR,
+        Self::PATTERN_PLUS,
+        Self::PATTERN_MINUS,
+        Self::PATTERN_SLASH,
+        Self::PATTERN_PERCENT,
+        Self::PATTERN_CONCAT,
         Self::PATTERN_COMMA,
+        Self::PATTERN_INTEGER,
+        Self::PATTERN_DECIMAL,
+        Self::PATTERN_SCIENTIFIC,
+        Self::PATTERN_HEX,
+        Self::PATTERN_STRING,
+        Self::PATTERN_ESTRING,
+        Self::PATTERN_COMMENT,
         Self::PATTERN_WHITESPACE,
+        Self::PATTERN_PARAM,
+        Self::PATTERN_QUOTED,
         Self::PATTERN_NAME,
     ];
 
-    pub fn new(text: &str) -> Self {
-        LexerPattern {
-            text: text.to_owned(),
-        }
+    pub fn new(text: &'a str) -> Self {
+        LexerPattern { text }
     }
 
-    fn into_lexer(re: Regex, value: LexerMatch) -> Option<Lexer> {
-        match re.as_str() {
+    fn into_lexer(expr: &str, value: LexerMatch<'a>) -> Option<Lexer<'a>> {
+        match expr {
             Self::PATTERN_SELECT => Some(Lexer::SELECT(value)),
             Self::PATTERN_FROM => Some(Lexer::FROM(value)),
+            Self::PATTERN_WHERE => Some(Lexer::WHERE(value)),
             Self::PATTERN_ALIAS => Some(Lexer::ALIAS(value)),
+            Self::PATTERN_AND => Some(Lexer::AND(value)),
+            Self::PATTERN_OR => Some(Lexer::OR(value)),
+            Self::PATTERN_NOT => Some(Lexer::NOT(value)),
+            Self::PATTERN_IS => Some(Lexer::IS(value)),
+            Self::PATTERN_NULL => Some(Lexer::NULL(value)),
+            Self::PATTERN_BETWEEN => Some(Lexer::BETWEEN(value)),
+            Self::PATTERN_IN => Some(Lexer::IN(value)),
+            Self::PATTERN_LT => Some(Lexer::LT(value)),
+            Self::PATTERN_GT => Some(Lexer::GT(value)),
+            Self::PATTERN_ORDER => Some(Lexer::ORDER(value)),
+            Self::PATTERN_BY => Some(Lexer::BY(value)),
+            Self::PATTERN_ASC => Some(Lexer::ASC(value)),
+            Self::PATTERN_DESC => Some(Lexer::DESC(value)),
+            Self::PATTERN_NULLS => Some(Lexer::NULLS(value)),
+            Self::PATTERN_FIRST => Some(Lexer::FIRST(value)),
+            Self::PATTERN_LAST => Some(Lexer::LAST(value)),
+            Self::PATTERN_LIMIT => Some(Lexer::LIMIT(value)),
+            Self::PATTERN_OFFSET => Some(Lexer::OFFSET(value)),
+            Self::PATTERN_FETCH => Some(Lexer::FETCH(value)),
+            Self::PATTERN_NEXT => Some(Lexer::NEXT(value)),
+            Self::PATTERN_ROW => Some(Lexer::ROW(value)),
+            Self::PATTERN_ROWS => Some(Lexer::ROWS(value)),
+            Self::PATTERN_ONLY => Some(Lexer::ONLY(value)),
+            Self::PATTERN_WITH => Some(Lexer::WITH(value)),
+            Self::PATTERN_TIES => Some(Lexer::TIES(value)),
+            Self::PATTERN_GROUP => Some(Lexer::GROUP(value)),
+            Self::PATTERN_HAVING => Some(Lexer::HAVING(value)),
+            Self::PATTERN_DISTINCT => Some(Lexer::DISTINCT(value)),
+            Self::PATTERN_JOIN => Some(Lexer::JOIN(value)),
+            Self::PATTERN_INNER => Some(Lexer::INNER(value)),
+            Self::PATTERN_LEFT => Some(Lexer::LEFT(value)),
+            Self::PATTERN_RIGHT => Some(Lexer::RIGHT(value)),
+            Self::PATTERN_FULL => Some(Lexer::FULL(value)),
+            Self::PATTERN_OUTER => Some(Lexer::OUTER(value)),
+            Self::PATTERN_CROSS => Some(Lexer::CROSS(value)),
+            Self::PATTERN_NATURAL => Some(Lexer::NATURAL(value)),
+            Self::PATTERN_ON => Some(Lexer::ON(value)),
+            Self::PATTERN_USING => Some(Lexer::USING(value)),
+            Self::PATTERN_EXISTS => Some(Lexer::EXISTS(value)),
+            Self::PATTERN_ANY => Some(Lexer::ANY(value)),
+            Self::PATTERN_ALL => Some(Lexer::ALL(value)),
+            Self::PATTERN_RECURSIVE => Some(Lexer::RECURSIVE(value)),
+            Self::PATTERN_MATERIALIZED => Some(Lexer::MATERIALIZED(value)),
+            Self::PATTERN_UNION => Some(Lexer::UNION(value)),
+            Self::PATTERN_INTERSECT => Some(Lexer::INTERSECT(value)),
+            Self::PATTERN_EXCEPT => Some(Lexer::EXCEPT(value)),
+            Self::PATTERN_LIKE => Some(Lexer::LIKE(value)),
+            Self::PATTERN_ILIKE => Some(Lexer::ILIKE(value)),
+            Self::PATTERN_SIMILAR => Some(Lexer::SIMILAR(value)),
+            Self::PATTERN_TO => Some(Lexer::TO(value)),
+            Self::PATTERN_EQ => Some(Lexer::EQ(value)),
+            Self::PATTERN_LE => Some(Lexer::LE(value)),
+            Self::PATTERN_GE => Some(Lexer::GE(value)),
+            Self::PATTERN_NE => Some(Lexer::NE(value)),
+            Self::PATTERN_LPAREN => Some(Lexer::LPAREN(value)),
+            Self::PATTERN_RPAREN => Some(Lexer::RPAREN(value)),
+            Self::PATTERN_DOT => Some(Lexer::DOT(value)),
+            Self::PATTERN_SEMICOLON => Some(Lexer::SEMICOLON(value)),
             Self::PATTERN_STAR => Some(Lexer::STAR(value)),
+            Self::PATTERN_PLUS => Some(Lexer::PLUS(value)),
+            Self::PATTERN_MINUS => Some(Lexer::MINUS(value)),
+            Self::PATTERN_SLASH => Some(Lexer::SLASH(value)),
+            Self::PATTERN_PERCENT => Some(Lexer::PERCENT(value)),
+            Self::PATTERN_CONCAT => Some(Lexer::CONCAT(value)),
             Self::PATTERN_COMMA => Some(Lexer::COMMA(value)),
+            Self::PATTERN_INTEGER => Some(Lexer::INTEGER(value)),
+            Self::PATTERN_DECIMAL => Some(Lexer::DECIMAL(value)),
+            Self::PATTERN_SCIENTIFIC => Some(Lexer::SCIENTIFIC(value)),
+            Self::PATTERN_HEX => Some(Lexer::HEX(value)),
+            Self::PATTERN_STRING => Some(Lexer::STRING(value)),
+            Self::PATTERN_ESTRING => Some(Lexer::ESTRING(value)),
+            Self::PATTERN_COMMENT => Some(Lexer::COMMENT(value)),
             Self::PATTERN_WHITESPACE => Some(Lexer::WHITESPACE(value)),
+            Self::PATTERN_PARAM => Some(Lexer::PARAM(value)),
+            Self::PATTERN_QUOTED => Some(Lexer::QUOTED(value)),
             Self::PATTERN_NAME => Some(Lexer::NAME(value)),
             _ => None,
         }
     }
 
-    fn match_pattern(&self, text_index: usize, patterns_index: usize) -> Option<Lexer> {
-        // get lexer
-        match Self::PATTERNS.get(patterns_index) {
-            // has lexer -> get regex -> match
-            Some(expr) => {
-                let re = Regex::new(expr).unwrap();
-                match re.find_at(&self.text, text_index) {
-                    // is match -> find next matches
-                    Some(mat) => {
-                        match self.match_pattern(text_index, patterns_index + 1) {
-                            // has next matches
-                            Some(next_lexer) => Some(
-                                // return next lexer if its len longer or its is fixed
-                                if next_lexer.value().range().len() > mat.range().len()
-                                    || next_lexer.is_value_ignores()
-                                {
-                                    next_lexer.to_owned()
-                                } else {
-                                    LexerPattern::into_lexer(
-                                        re,
-                                        LexerMatch::new_match(self.text.as_str(), &mat),
-                                    )
-                                    .unwrap()
-                                },
-                            ),
-                            None => Some(
-                                LexerPattern::into_lexer(
-                                    re,
-                                    LexerMatch::new_match(self.text.as_str(), &mat),
-                                )
-                                .unwrap(),
-                            ),
-                        }
-                    }
-                    None => self.match_pattern(text_index, patterns_index + 1),
+    /// The compiled regex of each pattern, in the order of `PATTERNS`
+    fn regexes() -> &'static [Regex] {
+        static REGEXES: OnceLock<Vec<Regex>> = OnceLock::new();
+        REGEXES.get_or_init(|| {
+            Self::PATTERNS
+                .iter()
+                .map(|expr| Regex::new(expr).unwrap())
+                .collect()
+        })
+    }
+
+    /// All patterns compiled at once, to find the candidates in a single pass
+    fn regex_set() -> &'static RegexSet {
+        static REGEX_SET: OnceLock<RegexSet> = OnceLock::new();
+        REGEX_SET.get_or_init(|| RegexSet::new(Self::PATTERNS).unwrap())
+    }
+
+    /// Matches the longest lexer at the text index, the earlier pattern wins on a tie
+    fn match_pattern(&self, text_index: usize) -> Option<Lexer<'a>> {
+        let text = &self.text[text_index..];
+        let mut longest: Option<(usize, usize)> = None;
+
+        for patterns_index in Self::regex_set().matches(text).iter() {
+            if let Some(mat) = Self::regexes()[patterns_index].find(text) {
+                if longest.is_none_or(|(_, len)| mat.end() > len) {
+                    longest = Some((patterns_index, mat.end()));
                 }
             }
-            None => None,
         }
+
+        longest.and_then(|(patterns_index, len)| {
+            Self::into_lexer(
+                Self::PATTERNS[patterns_index],
+                LexerMatch::new(self.text, text_index, text_index + len),
+            )
+        })
     }
 
-    pub fn match_text(&self, text_index: usize) -> Result<Vec<Lexer>, SyntaxError> {
-        // no end
-        if text_index < self.text.len() {
-            // find lexer
-            match self.match_pattern(text_index, 0) {
-                // has lexer
+    /// Matches a block comment at the text index, block comments may be nested
+    fn match_block_comment(&self, text_index: usize) -> Result<Option<Lexer<'a>>, SyntaxError<'a>> {
+        if !self.text[text_index..].starts_with("/*") {
+            return Ok(None);
+        }
+
+        let bytes = self.text.as_bytes();
+        let mut depth = 0_usize;
+        let mut i = text_index;
+        while i < bytes.len() {
+            if bytes[i..].starts_with(b"/*") {
+                depth += 1;
+                i += 2;
+            } else if bytes[i..].starts_with(b"*/") {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return Ok(Some(Lexer::COMMENT(LexerMatch::new(
+                        self.text, text_index, i,
+                    ))));
+                }
+            } else {
+                i += 1;
+            }
+        }
+
+        // report at the start of the outermost comment
+        Err(SyntaxError::new_lexer(
+            LexerMatch::new(self.text, text_index, text_index + 2),
+            "unterminated block comment",
+        )
+        .with_help("close the comment with */"))
+    }
+
+    /// Checks that a string literal at the text index is closed, an unterminated one would
+    /// be lexed as a name or an unexpected `'` otherwise
+    fn check_string(&self, text_index: usize) -> Result<(), SyntaxError<'a>> {
+        let text = &self.text[text_index..];
+        let quote_len = match text.as_bytes() {
+            [b'\'', ..] => 1,
+            [b'e' | b'E', b'\'', ..] => 2,
+            _ => return Ok(()),
+        };
+        let closed = Self::regex_set()
+            .matches(text)
+            .iter()
+            .any(|patterns_index| {
+                matches!(
+                    Self::PATTERNS[patterns_index],
+                    Self::PATTERN_STRING | Self::PATTERN_ESTRING
+                )
+            });
+        if closed {
+            return Ok(());
+        }
+
+        // report at the opening quote
+        Err(SyntaxError::new_lexer(
+            LexerMatch::new(self.text, text_index, text_index + quote_len),
+            "unterminated string literal",
+        )
+        .with_help("close the string with '"))
+    }
+
+    /// Returns an iterator over the lexers, whitespace and comments are skipped
+    pub fn iter(&self) -> LexerIter<'a> {
+        LexerIter {
+            pattern: *self,
+            text_index: 0,
+            recovering: false,
+        }
+    }
+
+    pub fn matches(&self) -> Result<Vec<Lexer<'a>>, SyntaxError<'a>> {
+        self.iter().collect()
+    }
+
+    /// Matches all lexers, an unexpected char is skipped instead of stopping the scan
+    pub fn matches_recovering(&self) -> (Vec<Lexer<'a>>, Vec<SyntaxError<'a>>) {
+        let mut lexers = vec![];
+        let mut errors = vec![];
+        let iter = LexerIter {
+            recovering: true,
+            ..self.iter()
+        };
+        for result in iter {
+            match result {
+                Ok(lexer) => lexers.push(lexer),
+                Err(err) => errors.push(err),
+            }
+        }
+        (lexers, errors)
+    }
+}
+
+/// A single pass scanner over the text of a `LexerPattern`
+///
+/// Each call of `next` matches one lexer, it stops after the first error
+/// unless it is recovering.
+#[derive(Debug, Clone)]
+pub struct LexerIter<'a> {
+    pattern: LexerPattern<'a>,
+    text_index: usize,
+    recovering: bool,
+}
+
+impl<'a> Iterator for LexerIter<'a> {
+    type Item = Result<Lexer<'a>, SyntaxError<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.pattern.text;
+        while self.text_index < text.len() {
+            let lexer = match self
+                .pattern
+                .check_string(self.text_index)
+                .and_then(|_| self.pattern.match_block_comment(self.text_index))
+            {
+                Ok(Some(comment)) => Some(comment),
+                Ok(None) => self.pattern.match_pattern(self.text_index),
+                Err(err) => {
+                    self.text_index = text.len();
+                    return Some(Err(err));
+                }
+            };
+            match lexer {
                 Some(lexer) => {
-                    // match next lexer
-                    match self.match_text(text_index + lexer.value().range().len()) {
-                        // has lexers
-                        Ok(lexers) => {
-                            // insert lexer if it is not whitespace
-                            if !matches!(lexer, Lexer::WHITESPACE(_)) {
-                                let mut mut_lexers = lexers;
-                                mut_lexers.insert(0, lexer);
-                                Ok(mut_lexers)
-                            } else {
-                                Ok(lexers)
-                            }
-                        }
-                        Err(err) => Err(err),
+                    self.text_index = lexer.value().end();
+                    // skip whitespace and comment
+                    if !matches!(lexer, Lexer::WHITESPACE(_) | Lexer::COMMENT(_)) {
+                        return Some(Ok(lexer));
                     }
                 }
                 // no lexer
-                None => Err(SyntaxError::new_excpeted(LexerMatch::new(
-                    &self.text,
-                    text_index,
-                    text_index + 1,
-                ))),
+                None => {
+                    let char_len = text[self.text_index..].chars().next().unwrap().len_utf8();
+                    let err = SyntaxError::new_excpeted(LexerMatch::new(
+                        text,
+                        self.text_index,
+                        self.text_index + char_len,
+                    ));
+                    self.text_index = match self.recovering {
+                        true => self.text_index + char_len,
+                        false => text.len(),
+                    };
+                    return Some(Err(err));
+                }
             }
-        } else {
-            // is end
-            Ok(vec![])
         }
-    }
-
-    pub fn matches(&self) -> Result<Vec<Lexer>, SyntaxError> {
-        self.match_text(0)
+        None
     }
 }
 
@@ -141,18 +468,237 @@ mod tests {
         assert!(LexerPattern::PATTERNS
             .iter()
             .any(|item| item == &LexerPattern::PATTERN_FROM));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_WHERE));
         assert!(LexerPattern::PATTERNS
             .iter()
             .any(|item| item == &LexerPattern::PATTERN_ALIAS));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_AND));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_OR));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_NOT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_IS));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_NULL));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_BETWEEN));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_IN));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_LT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_GT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_ORDER));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_BY));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_ASC));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_DESC));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_NULLS));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_FIRST));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_LAST));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_LIMIT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_OFFSET));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_FETCH));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_NEXT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_ROW));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_ROWS));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_ONLY));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_WITH));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_TIES));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_GROUP));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_HAVING));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_DISTINCT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_JOIN));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_INNER));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_LEFT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_RIGHT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_FULL));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_OUTER));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_CROSS));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_NATURAL));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_ON));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_USING));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_EXISTS));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_ANY));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_ALL));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_RECURSIVE));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_MATERIALIZED));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_UNION));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_INTERSECT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_EXCEPT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_LIKE));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_ILIKE));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_SIMILAR));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_TO));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_EQ));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_LE));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_GE));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_NE));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_LPAREN));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_RPAREN));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_DOT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_SEMICOLON));
         assert!(LexerPattern::PATTERNS
             .iter()
             .any(|item| item == &LexerPattern::PATTERN_STAR));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_PLUS));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_MINUS));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_SLASH));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_PERCENT));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_CONCAT));
         assert!(LexerPattern::PATTERNS
             .iter()
             .any(|item| item == &LexerPattern::PATTERN_COMMA));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_INTEGER));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_DECIMAL));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_SCIENTIFIC));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_HEX));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_STRING));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_ESTRING));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_COMMENT));
         assert!(LexerPattern::PATTERNS
             .iter()
             .any(|item| item == &LexerPattern::PATTERN_WHITESPACE));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_PARAM));
+        assert!(LexerPattern::PATTERNS
+            .iter()
+            .any(|item| item == &LexerPattern::PATTERN_QUOTED));
         assert!(LexerPattern::PATTERNS
             .iter()
             .any(|item| item == &LexerPattern::PATTERN_NAME));
@@ -168,62 +714,154 @@ mod tests {
     fn it_match_pattern() {
         // Lexer::SELECT
         assert!(matches!(
-            LexerPattern::new("SELECT").match_pattern(0, 0),
+            LexerPattern::new("SELECT").match_pattern(0),
             Some(lexer) if matches!(&lexer, Lexer::SELECT(value) if value.as_str() == "SELECT")
         ));
         assert!(matches!(
-            LexerPattern::new("select").match_pattern(0, 0),
+            LexerPattern::new("select").match_pattern(0),
             Some(lexer) if matches!(&lexer, Lexer::SELECT(value) if value.as_str() == "select")
         ));
         // Lexer::FROM
         assert!(matches!(
-            LexerPattern::new("FROM").match_pattern(0, 0),
+            LexerPattern::new("FROM").match_pattern(0),
             Some(lexer) if matches!(&lexer, Lexer::FROM(value) if value.as_str() == "FROM")
         ));
         assert!(matches!(
-            LexerPattern::new("from").match_pattern(0, 0),
+            LexerPattern::new("from").match_pattern(0),
             Some(lexer) if matches!(&lexer, Lexer::FROM(value) if value.as_str() == "from")
         ));
         // Lexer::AS
         assert!(matches!(
-            LexerPattern::new("AS").match_pattern(0, 0),
+            LexerPattern::new("AS").match_pattern(0),
             Some(lexer) if matches!(&lexer, Lexer::ALIAS(value) if value.as_str() == "AS")
         ));
         assert!(matches!(
-            LexerPattern::new("as").match_pattern(0, 0),
+            LexerPattern::new("as").match_pattern(0),
             Some(lexer) if matches!(&lexer, Lexer::ALIAS(value) if value.as_str() == "as")
         ));
+        // Lexer::DOT
+        assert!(matches!(
+            LexerPattern::new(".").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::DOT(value) if value.as_str() == ".")
+        ));
+        // Lexer::SEMICOLON
+        assert!(matches!(
+            LexerPattern::new(";").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::SEMICOLON(value) if value.as_str() == ";")
+        ));
         // Lexer::STAR
         assert!(matches!(
-            LexerPattern::new("*").match_pattern(0, 0),
+            LexerPattern::new("*").match_pattern(0),
             Some(lexer) if matches!(&lexer, Lexer::STAR(value) if value.as_str() == "*")
         ));
         // Lexer::COMMA
         assert!(matches!(
-            LexerPattern::new(",").match_pattern(0, 0),
+            LexerPattern::new(",").match_pattern(0),
             Some(lexer) if matches!(&lexer, Lexer::COMMA(value) if value.as_str() == ",")
         ));
         // Lexer::WHITESPACE
         assert!(matches!(
-            LexerPattern::new("' \r\n\t'").match_pattern(0, 0),
+            LexerPattern::new(" \r\n\t").match_pattern(0),
             Some(lexer) if matches!(&lexer, Lexer::WHITESPACE(value) if value.as_str() == " \r\n\t")
         ));
+        // Lexer::INTEGER
+        assert!(matches!(
+            LexerPattern::new("0123").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::INTEGER(value) if value.as_str() == "0123")
+        ));
+        // Lexer::DECIMAL
+        assert!(matches!(
+            LexerPattern::new("1.5").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::DECIMAL(value) if value.as_str() == "1.5")
+        ));
+        assert!(matches!(
+            LexerPattern::new("1.").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::DECIMAL(value) if value.as_str() == "1.")
+        ));
+        assert!(matches!(
+            LexerPattern::new(".5").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::DECIMAL(value) if value.as_str() == ".5")
+        ));
+        // Lexer::SCIENTIFIC
+        assert!(matches!(
+            LexerPattern::new("1e10").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::SCIENTIFIC(value) if value.as_str() == "1e10")
+        ));
+        assert!(matches!(
+            LexerPattern::new("1.5E-3").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::SCIENTIFIC(value) if value.as_str() == "1.5E-3")
+        ));
+        // Lexer::HEX
+        assert!(matches!(
+            LexerPattern::new("0xFf").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::HEX(value) if value.as_str() == "0xFf")
+        ));
+        // Lexer::STRING
+        assert!(matches!(
+            LexerPattern::new("'it''s' x").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::STRING(value) if value.as_str() == "'it''s'")
+        ));
+        assert!(LexerPattern::new("'abc").match_pattern(0).is_none());
+        // Lexer::ESTRING
+        assert!(matches!(
+            LexerPattern::new(r"E'\'a''' x").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::ESTRING(value) if value.as_str() == r"E'\'a'''")
+        ));
+        assert!(matches!(
+            LexerPattern::new("e").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::NAME(value) if value.as_str() == "e")
+        ));
+        // Lexer::COMMENT
+        assert!(matches!(
+            LexerPattern::new("-- a * b\nc").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::COMMENT(value) if value.as_str() == "-- a * b")
+        ));
+        // Lexer::QUOTED
+        assert!(matches!(
+            LexerPattern::new(r#""Order ""Details""" x"#).match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::QUOTED(value) if value.as_str() == r#""Order ""Details""""#)
+        ));
+        assert!(matches!(
+            LexerPattern::new("`us``er` x").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::QUOTED(value) if value.as_str() == "`us``er`")
+        ));
+        assert!(LexerPattern::new(r#""""#).match_pattern(0).is_none());
+        // Lexer::PARAM
+        assert!(matches!(
+            LexerPattern::new("? x").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::PARAM(value) if value.as_str() == "?")
+        ));
+        assert!(matches!(
+            LexerPattern::new("$12 x").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::PARAM(value) if value.as_str() == "$12")
+        ));
+        assert!(matches!(
+            LexerPattern::new(":page_size x").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::PARAM(value) if value.as_str() == ":page_size")
+        ));
+        assert!(LexerPattern::new("$x").match_pattern(0).is_none());
+        // Lexer::ROWS, the longest keyword wins
+        assert!(matches!(
+            LexerPattern::new("rows").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::ROWS(value) if value.as_str() == "rows")
+        ));
+        assert!(LexerPattern::new("`abc").match_pattern(0).is_none());
         // Lexer::NAME
         assert!(matches!(
-            LexerPattern::new("aA1_").match_pattern(0, 0),
+            LexerPattern::new("aA1_").match_pattern(0),
             Some(lexer) if matches!(&lexer, Lexer::NAME(value) if value.as_str() == "aA1_")
         ));
         assert!(matches!(
-            LexerPattern::new("Aa1_").match_pattern(0, 0),
+            LexerPattern::new("Aa1_").match_pattern(0),
             Some(lexer) if matches!(&lexer, Lexer::NAME(value) if value.as_str() == "Aa1_")
         ));
         assert!(matches!(
-            LexerPattern::new("_aA1").match_pattern(0, 0),
-            None
+            LexerPattern::new("_aA1").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::NAME(value) if value.as_str() == "_aA1")
         ));
         assert!(matches!(
-            LexerPattern::new("1aA_").match_pattern(0, 0),
-            None
+            LexerPattern::new("1aA_").match_pattern(0),
+            Some(lexer) if matches!(&lexer, Lexer::INTEGER(value) if value.as_str() == "1")
         ));
     }
 
@@ -234,7 +872,7 @@ mod tests {
             LexerPattern::new("select * from table_1").matches(),
             Ok(lexers) if
                 lexers.len() == 4 &&
-                matches!(lexers.get(0), Some(lexer) if matches!(lexer, Lexer::SELECT(value) if value.as_str() == "select")) &&
+                matches!(lexers.first(), Some(lexer) if matches!(lexer, Lexer::SELECT(value) if value.as_str() == "select")) &&
                 matches!(lexers.get(1), Some(lexer) if matches!(lexer, Lexer::STAR(value) if value.as_str() == "*")) &&
                 matches!(lexers.get(2), Some(lexer) if matches!(lexer, Lexer::FROM(value) if value.as_str() == "from")) &&
                 matches!(lexers.get(3), Some(lexer) if matches!(lexer, Lexer::NAME(value) if value.as_str() == "table_1"))
@@ -244,15 +882,115 @@ mod tests {
             LexerPattern::new("SELECT * FROM TABLE_1").matches(),
             Ok(lexers) if
                 lexers.len() == 4 &&
-                matches!(lexers.get(0), Some(lexer) if matches!(lexer, Lexer::SELECT(value) if value.as_str() == "SELECT")) &&
+                matches!(lexers.first(), Some(lexer) if matches!(lexer, Lexer::SELECT(value) if value.as_str() == "SELECT")) &&
                 matches!(lexers.get(1), Some(lexer) if matches!(lexer, Lexer::STAR(value) if value.as_str() == "*")) &&
                 matches!(lexers.get(2), Some(lexer) if matches!(lexer, Lexer::FROM(value) if value.as_str() == "FROM")) &&
                 matches!(lexers.get(3), Some(lexer) if matches!(lexer, Lexer::NAME(value) if value.as_str() == "TABLE_1"))
         ));
+        // literals
+        assert!(matches!(
+            LexerPattern::new("SELECT 1, 'a'").matches(),
+            Ok(lexers) if
+                lexers.len() == 4 &&
+                matches!(lexers.get(1), Some(lexer) if matches!(lexer, Lexer::INTEGER(value) if value.as_str() == "1")) &&
+                matches!(lexers.get(3), Some(lexer) if matches!(lexer, Lexer::STRING(value) if value.as_str() == "'a'"))
+        ));
+        // qualified names
+        assert!(matches!(
+            LexerPattern::new("t1.id").matches(),
+            Ok(lexers) if
+                lexers.len() == 3 &&
+                matches!(lexers.first(), Some(lexer) if matches!(lexer, Lexer::NAME(value) if value.as_str() == "t1")) &&
+                matches!(lexers.get(1), Some(lexer) if matches!(lexer, Lexer::DOT(value) if value.as_str() == ".")) &&
+                matches!(lexers.get(2), Some(lexer) if matches!(lexer, Lexer::NAME(value) if value.as_str() == "id"))
+        ));
+        // comments
+        assert!(matches!(
+            LexerPattern::new("-- header\nSELECT /* a /* nested */ b */ *").matches(),
+            Ok(lexers) if
+                lexers.len() == 2 &&
+                matches!(lexers.first(), Some(lexer) if matches!(lexer, Lexer::SELECT(value) if value.range() == (10..16))) &&
+                matches!(lexers.get(1), Some(lexer) if matches!(lexer, Lexer::STAR(value) if value.range() == (40..41)))
+        ));
+        assert!(matches!(
+            LexerPattern::new("SELECT /* a /* b */ *").matches(),
+            Err(err) if err.cause == "unterminated block comment" && err.start == 7 && err.end == 9
+        ));
+        // unterminated strings
+        assert!(matches!(
+            LexerPattern::new("SELECT 'a FROM t").matches(),
+            Err(err) if err.cause == "unterminated string literal" && err.start == 7 && err.end == 8
+                && err.help.as_deref() == Some("close the string with '")
+        ));
+        assert!(matches!(
+            LexerPattern::new(r"SELECT e'a\' FROM t").matches(),
+            Err(err) if err.cause == "unterminated string literal" && err.start == 7 && err.end == 9
+        ));
+        assert!(matches!(
+            LexerPattern::new("SELECT e, 'a', E'b'").matches(),
+            Ok(lexers) if lexers.len() == 6
+        ));
+        // operators, `--` starts a comment, `1e-3` is one number
+        assert!(matches!(
+            LexerPattern::new("a||-1e-3/b%c - -d--e").matches(),
+            Ok(lexers) if
+                lexers.len() == 11 &&
+                matches!(lexers.get(1), Some(Lexer::CONCAT(value)) if value.as_str() == "||") &&
+                matches!(lexers.get(2), Some(Lexer::MINUS(_))) &&
+                matches!(lexers.get(3), Some(Lexer::SCIENTIFIC(value)) if value.as_str() == "1e-3") &&
+                matches!(lexers.get(4), Some(Lexer::SLASH(_))) &&
+                matches!(lexers.get(6), Some(Lexer::PERCENT(_))) &&
+                matches!(lexers.get(8), Some(Lexer::MINUS(_))) &&
+                matches!(lexers.get(9), Some(Lexer::MINUS(_)))
+        ));
         // err
         assert!(matches!(
-            LexerPattern::new("select * from _table1").matches(),
-            Err(err) if err.cause == "expected _"
+            LexerPattern::new("select * from #table1").matches(),
+            Err(err) if err.cause == "expected #"
+        ));
+    }
+
+    #[test]
+    fn it_matches_recovering() {
+        let (lexers, errors) = LexerPattern::new("SELECT # a ¤ FROM t").matches_recovering();
+        assert_eq!(lexers.len(), 4);
+        assert!(matches!(
+            errors.as_slice(),
+            [first, second] if first.cause == "expected #" && second.cause == "expected ¤"
+                && second.start == 11 && second.end == 13
+        ));
+        // an unterminated comment runs to the end
+        let (lexers, errors) = LexerPattern::new("SELECT a /* FROM t").matches_recovering();
+        assert_eq!(lexers.len(), 2);
+        assert_eq!(errors.len(), 1);
+        // so does an unterminated string
+        let (lexers, errors) = LexerPattern::new("SELECT a, 'b FROM t").matches_recovering();
+        assert_eq!(lexers.len(), 3);
+        assert!(matches!(
+            errors.as_slice(),
+            [err] if err.cause == "unterminated string literal" && err.start == 10
+        ));
+    }
+
+    #[test]
+    fn it_iter() {
+        // lexers are yielded before the error
+        let pattern = LexerPattern::new("SELECT a # b");
+        let mut iter = pattern.iter();
+        assert!(matches!(iter.next(), Some(Ok(Lexer::SELECT(_)))));
+        assert!(matches!(iter.next(), Some(Ok(Lexer::NAME(_)))));
+        assert!(matches!(iter.next(), Some(Err(err)) if err.cause == "expected #"));
+        assert!(iter.next().is_none());
+        // multibyte char in error
+        assert!(matches!(
+            LexerPattern::new("a ¤").matches(),
+            Err(err) if err.cause == "expected ¤"
+        ));
+        // large input
+        let text = format!("SELECT a FROM t WHERE a IN ({}1)", "1, ".repeat(50_000));
+        assert!(matches!(
+            LexerPattern::new(&text).matches(),
+            Ok(lexers) if lexers.len() == 100_010
         ));
     }
 }
@@ -1,7 +1,23 @@
+//! SQL lexer, parser and AST
+//!
+//! With the `serde` feature the AST nodes and `SyntaxError` implement `Serialize` and
+//! `Deserialize`. The representation is part of the API:
+//!
+//! - structs are objects of their fields, e.g. `{"parts": [...], "span": ...}`
+//! - enums with data are tagged with the variant name, `{"type": "SELECT", "value": {...}}`,
+//!   a variant of several fields has an array value, e.g.
+//!   `{"type": "INTEGER", "value": ["42", {"start": 7, "end": 9}]}`
+//! - operators are strings of the variant name, e.g. `"EQ"` or `"AND"`
+//! - spans are byte offsets, `{"start": 7, "end": 9}`
+//! - `SyntaxError::expected` is written but not read back
+
 pub mod clause;
 pub mod err;
 pub mod expr;
+pub mod format;
 pub mod frag;
 pub mod lexer;
 pub mod parser;
+pub mod span;
 pub mod stmt;
+pub mod visit;
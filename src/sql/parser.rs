@@ -1,40 +1,602 @@
+use std::cell::RefCell;
+
 use super::{
     err::SyntaxError,
-    lexer::{lexer::Lexer, pattern::LexerPattern},
-    stmt::stmt::Stmt,
+    lexer::{lexer::Lexer, mat::LexerMatch, pattern::LexerPattern},
+    span::Span,
+    stmt::{query::QueryStmt, stmt::Stmt},
 };
 
-pub trait LexerParser {
-    fn parse(source: &SyntaxPattern, index: usize) -> Result<(Self, usize), SyntaxError>
+/// Parses a syntax from the lexers at `index`, the syntax borrows the source text
+pub trait LexerParser<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>>
     where
         Self: Sized;
 }
 
+/// The lexers a syntax may start with, they are expected where the syntax is missing
+/// at the end of the input, e.g. after a trailing `,` of an item list
+pub trait LexerStart {
+    const START: &'static [&'static str];
+}
+
+/// Lexers of the source text, it also keeps the furthest index the parsers
+/// have looked at and what they would have accepted there
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SyntaxPattern {
-    pub text: String,
-    pub items: Vec<Lexer>,
+pub struct SyntaxPattern<'a> {
+    pub text: &'a str,
+    pub items: Vec<Lexer<'a>>,
+    expected: RefCell<(usize, Vec<&'static str>)>,
 }
 
-impl SyntaxPattern {
-    pub fn new(text: &str, items: Vec<Lexer>) -> Self {
+impl<'a> SyntaxPattern<'a> {
+    pub fn new(text: &'a str, items: Vec<Lexer<'a>>) -> Self {
         SyntaxPattern {
-            text: text.to_owned(),
+            text,
             items,
+            expected: RefCell::new((0, vec![])),
+        }
+    }
+
+    pub fn first(&self) -> Option<&Lexer<'a>> {
+        self.items.first()
+    }
+
+    /// Records the lexers which would have been accepted at `index`,
+    /// lexers recorded at a smaller index are dropped
+    pub fn expect(&self, index: usize, expected: &[&'static str]) {
+        let mut furthest = self.expected.borrow_mut();
+        if index > furthest.0 {
+            *furthest = (index, vec![]);
+        }
+        if index == furthest.0 {
+            for name in expected {
+                if !furthest.1.contains(name) {
+                    furthest.1.push(name);
+                }
+            }
+        }
+    }
+
+    /// Byte range of the lexers from `index` until `end_index` (exclusive)
+    pub fn span(&self, index: usize, end_index: usize) -> Span {
+        let start = match self.items.get(index) {
+            Some(lexer) => lexer.value().start(),
+            None => self.text.len(),
+        };
+        match end_index > index {
+            true => match self.items.get(end_index - 1) {
+                Some(lexer) => Span::new(start, lexer.value().end()),
+                None => Span::new(start, self.text.len()),
+            },
+            false => Span::new(start, start),
         }
     }
 
-    pub fn first(&self) -> Option<&Lexer> {
-        self.items.get(0)
+    /// The furthest index the parsers have looked at
+    pub fn furthest(&self) -> usize {
+        self.expected.borrow().0
+    }
+
+    /// Records the expected lexers at `index` and returns a missing error there
+    pub fn missing(&self, index: usize, name: &str, expected: &[&'static str]) -> SyntaxError<'a> {
+        self.expect(index, expected);
+        match self.items.get(index) {
+            Some(lexer) => SyntaxError::new_missing(lexer.value(), name),
+            None => SyntaxError::new_missing(LexerMatch::new_eof(self.text), name),
+        }
+    }
+
+    /// Returns an error for a well formed but invalid construct, e.g. an out of range
+    /// position, the lexers expected so far do not explain it and are dropped
+    pub fn invalid(&self, span: Span, cause: &str) -> SyntaxError<'a> {
+        self.expected.borrow_mut().1.clear();
+        SyntaxError::new_lexer(LexerMatch::new(self.text, span.start, span.end), cause)
+    }
+
+    /// Replaces the error with the lexers expected at the furthest index, if any
+    pub fn expected_error(&self, err: SyntaxError<'a>) -> SyntaxError<'a> {
+        let furthest = self.expected.borrow();
+        if furthest.1.is_empty() {
+            return err;
+        }
+        // kinds like identifier first, then keywords, then punctuation
+        let mut expected = furthest.1.clone();
+        expected.sort_by_key(|name| match name.chars().next() {
+            Some('\'') => (2, *name),
+            Some(c) if c.is_lowercase() => (0, *name),
+            _ => (1, *name),
+        });
+        match self.items.get(furthest.0) {
+            Some(lexer) => SyntaxError::new_expected_one_of(lexer.value(), expected, lexer.label()),
+            None => SyntaxError::new_expected_one_of(
+                LexerMatch::new_eof(self.text),
+                expected,
+                "end of input",
+            ),
+        }
     }
 }
 
-pub fn parse_stmt(sql: &str) -> Result<Stmt, SyntaxError> {
+/// Parses a single statement, an optional `;` may follow it, nothing else may
+pub fn parse_stmt(sql: &str) -> Result<Stmt<'_>, SyntaxError<'_>> {
     match LexerPattern::new(sql).matches() {
-        Ok(lexers) => match Stmt::parse(&SyntaxPattern::new(sql, lexers), 0) {
-            Ok((stmt, _)) => Ok(stmt),
-            Err(err) => Err(err),
-        },
+        Ok(lexers) => {
+            let source = SyntaxPattern::new(sql, lexers);
+            let (stmt, end_index) =
+                Stmt::parse(&source, 0).map_err(|err| source.expected_error(err))?;
+            let end_index = match source.items.get(end_index) {
+                Some(Lexer::SEMICOLON(_)) => end_index + 1,
+                _ => {
+                    source.expect(end_index, &["';'"]);
+                    end_index
+                }
+            };
+            match source.items.get(end_index) {
+                Some(lexer) => {
+                    source.expect(end_index, &[]);
+                    Err(source.expected_error(SyntaxError::new_unexpected(lexer.value())))
+                }
+                None => Ok(stmt),
+            }
+        }
         Err(err) => Err(err),
     }
 }
+
+/// Parses statements separated by `;`, empty statements are skipped
+pub fn parse_script(sql: &str) -> Result<Vec<Stmt<'_>>, SyntaxError<'_>> {
+    match LexerPattern::new(sql).matches() {
+        Ok(lexers) => {
+            let source = SyntaxPattern::new(sql, lexers);
+            let mut stmts = vec![];
+            let mut index = 0;
+            while index < source.items.len() {
+                // empty statement
+                if let Some(Lexer::SEMICOLON(_)) = source.items.get(index) {
+                    index += 1;
+                    continue;
+                }
+                let (stmt, end_index) = parse_script_stmt(&source, index)?;
+                stmts.push(stmt);
+                index = end_index;
+            }
+            Ok(stmts)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Statements and errors of a script parsed by `parse_script_recovering`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveredScript<'a> {
+    /// Statements without errors
+    pub stmts: Vec<Stmt<'a>>,
+    /// What could be parsed of the statements with errors
+    pub partial_stmts: Vec<Stmt<'a>>,
+    /// Lexer and syntax errors in the order of the text
+    pub errors: Vec<SyntaxError<'a>>,
+}
+
+/// Parses statements like `parse_script`, but does not stop at the first error
+///
+/// After a syntax error the parser skips to the next `SELECT`, `WITH`, `FROM` or `;`. A
+/// `FROM` continues the broken statement, it is parsed from there on and kept as a partial
+/// statement whose first select has no select clause, `None` marks the missing list. A statement with a lexer error in it
+/// is a partial statement as well, the lexers around the error may not mean what they
+/// were meant to, e.g. the rest of an unterminated string.
+pub fn parse_script_recovering(sql: &str) -> RecoveredScript<'_> {
+    let (lexers, lexer_errors) = LexerPattern::new(sql).matches_recovering();
+    let source = SyntaxPattern::new(sql, lexers);
+    let mut script = RecoveredScript {
+        stmts: vec![],
+        partial_stmts: vec![],
+        errors: vec![],
+    };
+    let mut index = 0;
+    while index < source.items.len() {
+        // empty statement
+        if let Some(Lexer::SEMICOLON(_)) = source.items.get(index) {
+            index += 1;
+            continue;
+        }
+        match parse_script_stmt(&source, index) {
+            Ok((stmt, end_index)) => {
+                let span = source.span(index, end_index);
+                match lexer_errors
+                    .iter()
+                    .any(|err| span.start <= err.start && err.start < span.end)
+                {
+                    true => script.partial_stmts.push(stmt),
+                    false => script.stmts.push(stmt),
+                }
+                index = end_index;
+            }
+            Err(err) => {
+                script.errors.push(err);
+                index = recover(&source, source.furthest().max(index + 1), &mut script);
+            }
+        }
+    }
+    // lexer errors come first, keep them in the order of the text
+    script.errors.extend(lexer_errors);
+    script.errors.sort_by_key(|err| err.start);
+    script
+}
+
+/// Parses a statement followed by `;` or the end, returns the index after the `;`
+fn parse_script_stmt<'a>(
+    source: &SyntaxPattern<'a>,
+    index: usize,
+) -> Result<(Stmt<'a>, usize), SyntaxError<'a>> {
+    let (stmt, end_index) = Stmt::parse(source, index).map_err(|err| source.expected_error(err))?;
+    match source.items.get(end_index) {
+        Some(Lexer::SEMICOLON(_)) | None => Ok((stmt, end_index + 1)),
+        Some(lexer) => {
+            source.expect(end_index, &["';'"]);
+            let err = SyntaxError::new_unexpected(lexer.value());
+            Err(source.expected_error(err))
+        }
+    }
+}
+
+/// Skips lexers until a statement may start again, returns the index to go on with
+///
+/// A `FROM` continues the broken statement, it is parsed as a query from there on to
+/// report the errors of its clauses as well.
+fn recover<'a>(
+    source: &SyntaxPattern<'a>,
+    index: usize,
+    script: &mut RecoveredScript<'a>,
+) -> usize {
+    let mut index = index;
+    loop {
+        match source.items.get(index) {
+            None => return index,
+            Some(Lexer::SEMICOLON(_)) => return index + 1,
+            Some(Lexer::SELECT(_)) => return index,
+            // WITH TIES belongs to a FETCH clause
+            Some(Lexer::WITH(_))
+                if !matches!(source.items.get(index + 1), Some(Lexer::TIES(_))) =>
+            {
+                return index
+            }
+            Some(Lexer::FROM(_)) => match QueryStmt::parse_from(source, index) {
+                Ok((query, end_index)) => {
+                    script.partial_stmts.push(Stmt::from(query));
+                    match source.items.get(end_index) {
+                        Some(Lexer::SEMICOLON(_)) | None => return end_index + 1,
+                        Some(lexer) => {
+                            source.expect(end_index, &["';'"]);
+                            let err = SyntaxError::new_unexpected(lexer.value());
+                            script.errors.push(source.expected_error(err));
+                            index = end_index;
+                        }
+                    }
+                }
+                Err(err) => {
+                    script.errors.push(source.expected_error(err));
+                    index = source.furthest().max(index + 1);
+                }
+            },
+            Some(_) => index += 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::{
+        expr::expr::Expr,
+        span::{eq_ignore_span, Spanned},
+        stmt::{query::QueryStmt, stmt::Stmt},
+    };
+
+    use super::{parse_script, parse_script_recovering, parse_stmt, RecoveredScript};
+
+    #[test]
+    fn it_parse_stmt() {
+        // one statement
+        assert!(parse_stmt("SELECT a FROM t").is_ok());
+        assert!(parse_stmt("SELECT a FROM t;").is_ok());
+        // non reserved keywords as names
+        assert!(matches!(
+            parse_stmt("SELECT recursive, materialized FROM recursive"),
+            Ok(stmt) if stmt.to_string() == "SELECT recursive, materialized FROM recursive"
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT ilike FROM t WHERE ilike ILIKE 'a%'"),
+            Ok(stmt) if stmt.to_string() == "SELECT ilike FROM t WHERE ilike ILIKE 'a%'"
+        ));
+        // reserved keywords
+        for sql in [
+            "SELECT any FROM t",
+            "SELECT similar FROM t",
+            "SELECT to FROM t",
+        ] {
+            assert!(parse_stmt(sql).is_err(), "{}", sql);
+        }
+        // trailing lexers
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t garbage"),
+            Err(err) if err.cause == "expected one of AS, CROSS, EXCEPT, FETCH, FULL, GROUP, HAVING, INNER, INTERSECT, JOIN, LEFT, LIMIT, NATURAL, OFFSET, ORDER, RIGHT, UNION, WHERE, ',', ';', got identifier"
+                && err.start == 16 && err.end == 23
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t; SELECT b FROM t"),
+            Err(err) if err.cause == "unexpected SELECT" && err.start == 17
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t;;"),
+            Err(err) if err.cause == "unexpected ;"
+        ));
+    }
+
+    #[test]
+    fn it_span() {
+        let sql = "SELECT a AS x, t.* FROM t1 WHERE b = 1 AND NOT c IS NULL;";
+        let Stmt::SELECT(stmt) = parse_stmt(sql).unwrap() else {
+            panic!("not a select statement");
+        };
+        assert_eq!(stmt.span.range(), 0..56);
+        let select_clause = stmt.select_clause.as_ref().unwrap();
+        assert_eq!(select_clause.span.range(), 0..18);
+        assert_eq!(select_clause.items.span.range(), 7..18);
+        let items = &select_clause.items.items;
+        assert_eq!(items[0].span.range(), 7..13);
+        assert_eq!(items[0].value.span().range(), 7..8);
+        assert_eq!(items[0].alias.as_ref().unwrap().span.range(), 12..13);
+        assert_eq!(items[1].value.span().range(), 15..18);
+        assert_eq!(stmt.from_clause.span.range(), 19..26);
+        let where_clause = stmt.where_clause.unwrap();
+        assert_eq!(where_clause.span.range(), 27..56);
+        assert_eq!(where_clause.expr.span().range(), 33..56);
+        let Expr::BINARY(expr) = where_clause.expr else {
+            panic!("not a binary expression");
+        };
+        assert_eq!(expr.left.span().range(), 33..38);
+        assert_eq!(expr.right.span().range(), 43..56);
+        // spans are ignored by equality
+        assert!(eq_ignore_span(
+            &parse_stmt("SELECT a FROM t").unwrap(),
+            &parse_stmt("SELECT  a\nFROM  t").unwrap()
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_serde() {
+        use crate::sql::{err::SyntaxError, frag::select_item::SelectItem};
+
+        let stmt = parse_stmt("SELECT a AS x, 1 FROM t WHERE b IS NOT NULL").unwrap();
+        let json = serde_json::to_string(&stmt).unwrap();
+        assert!(json.starts_with(
+            r#"{"type":"SELECT","value":{"with_clause":null,"select_clause":{"quantifier":null,"items":"#
+        ));
+        assert_eq!(serde_json::from_str::<Stmt>(&json).unwrap(), stmt);
+        let Stmt::SELECT(select) = &stmt else {
+            panic!("not a select statement");
+        };
+        assert_eq!(
+            serde_json::to_string(&select.select_clause.as_ref().unwrap().items.items[1].value)
+                .unwrap(),
+            r#"{"type":"EXPR","value":{"type":"LITERAL","value":{"type":"INTEGER","value":["1",{"start":15,"end":16}]}}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<SelectItem>(r#"{"type":"STAR","value":{"start":0,"end":1}}"#)
+                .unwrap()
+                .to_string(),
+            "*"
+        );
+        let stmt = parse_stmt("SELECT DISTINCT ON (a) b FROM t").unwrap();
+        let json = serde_json::to_string(&stmt).unwrap();
+        assert!(json.contains(r#""quantifier":{"type":"DISTINCTON","value":{"items":"#));
+        assert_eq!(serde_json::from_str::<Stmt>(&json).unwrap(), stmt);
+        // errors
+        let err = parse_stmt("SELECT a b").unwrap_err();
+        let json = serde_json::to_string(&err).unwrap();
+        assert!(json.contains(r#""expected":["operator","AS","FROM","','"]"#));
+        let de = serde_json::from_str::<SyntaxError>(&json).unwrap();
+        assert_eq!(
+            (de.cause, de.start, de.end),
+            (err.cause, err.start, err.end)
+        );
+        assert!(de.expected.is_empty());
+    }
+
+    #[test]
+    fn it_expected() {
+        assert!(matches!(
+            parse_stmt("SELECT a b FROM t"),
+            Err(err) if err.cause == "expected one of operator, AS, FROM, ',', got identifier"
+                && err.expected == ["operator", "AS", "FROM", "','"] && err.start == 9
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT a FROM 1"),
+            Err(err) if err.cause == "expected one of identifier, '(', got integer"
+                && err.expected == ["identifier", "'('"]
+        ));
+        assert!(matches!(
+            parse_stmt("FROM t"),
+            Err(err) if err.cause == "expected one of SELECT, WITH, got FROM"
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t WHERE b IS 1"),
+            Err(err) if err.cause == "expected one of DISTINCT, NOT, NULL, got integer"
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t WHERE (b = 1"),
+            Err(err) if err.cause == "expected one of operator, ')', got end of input"
+        ));
+        // an item is expected at the end of an item list
+        assert!(matches!(
+            parse_stmt("SELECT a,"),
+            Err(err) if err.cause == "expected one of identifier, literal, parameter, NOT, '(', '*', '-', got end of input"
+                && err.start == 8
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT a FROM"),
+            Err(err) if err.cause == "expected one of identifier, '(', got end of input"
+                && err.expected == ["identifier", "'('"]
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT"),
+            Err(err) if err.cause == "expected one of identifier, literal, parameter, ALL, DISTINCT, NOT, '(', '*', '-', got end of input"
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t GROUP BY a,"),
+            Err(err) if err.expected == ["identifier", "literal", "parameter", "NOT", "'('", "'-'"]
+        ));
+    }
+
+    #[test]
+    fn it_parse_script() {
+        // no statement
+        assert!(matches!(parse_script(""), Ok(stmts) if stmts.is_empty()));
+        assert!(matches!(parse_script(" ; ;"), Ok(stmts) if stmts.is_empty()));
+        // statements
+        assert!(matches!(
+            parse_script("SELECT a FROM t; SELECT b FROM t"),
+            Ok(stmts) if stmts.len() == 2
+        ));
+        assert!(matches!(
+            parse_script("-- migration\nSELECT a FROM t;\n\nSELECT b FROM t;\n"),
+            Ok(stmts) if stmts.len() == 2 && eq_ignore_span(&stmts[0], &parse_stmt("SELECT a FROM t").unwrap())
+        ));
+        // missing semicolon
+        assert!(matches!(
+            parse_script("SELECT a FROM t SELECT b FROM t"),
+            Err(err) if err.cause == "expected one of AS, CROSS, EXCEPT, FETCH, FULL, GROUP, HAVING, INNER, INTERSECT, JOIN, LEFT, LIMIT, NATURAL, OFFSET, ORDER, RIGHT, UNION, WHERE, ',', ';', got SELECT" && err.start == 16
+        ));
+        // error in a later statement
+        assert!(matches!(
+            parse_script("SELECT a FROM t; SELECT FROM t"),
+            Err(err) if err.cause == "expected one of identifier, literal, parameter, ALL, DISTINCT, NOT, '(', '*', '-', got FROM"
+        ));
+    }
+
+    #[test]
+    fn it_parse_script_recovering() {
+        // no error
+        let RecoveredScript { stmts, errors, .. } =
+            parse_script_recovering("SELECT a FROM t; SELECT b FROM t");
+        assert_eq!(stmts.len(), 2);
+        assert!(errors.is_empty());
+        // one error per statement
+        let RecoveredScript { stmts, errors, .. } = parse_script_recovering(
+            "SELECT a b FROM t; SELECT c FROM 1; SELECT d FROM t; SELECT # FROM t",
+        );
+        assert!(eq_ignore_span(
+            &stmts,
+            &vec![parse_stmt("SELECT d FROM t").unwrap()]
+        ));
+        assert!(matches!(
+            errors.as_slice(),
+            [first, second, lexer, third] if lexer.cause == "expected #"
+                && first.cause == "expected one of operator, AS, FROM, ',', got identifier" && first.start == 9
+                && second.cause == "expected one of identifier, '(', got integer" && second.start == 33
+                && third.cause == "expected one of identifier, literal, parameter, ALL, DISTINCT, NOT, '(', '*', '-', got FROM"
+        ));
+        // errors in the select list and the where clause of one statement
+        let RecoveredScript { stmts, errors, .. } =
+            parse_script_recovering("SELECT 1 2 FROM t WHERE a IS 3");
+        assert!(stmts.is_empty());
+        assert!(matches!(
+            errors.as_slice(),
+            [first, second] if first.start == 9
+                && second.cause == "expected one of DISTINCT, NOT, NULL, got integer" && second.start == 29
+        ));
+        // the set operation belongs to the broken statement
+        let RecoveredScript { stmts, errors, .. } =
+            parse_script_recovering("SELECT 1 2 FROM t UNION SELECT b FROM u; SELECT c FROM t");
+        assert!(eq_ignore_span(
+            &stmts,
+            &vec![parse_stmt("SELECT c FROM t").unwrap()]
+        ));
+        assert_eq!(errors.len(), 1);
+        // missing semicolon
+        let RecoveredScript { stmts, errors, .. } =
+            parse_script_recovering("SELECT a FROM t SELECT b FROM t WHERE SELECT c FROM t");
+        assert!(eq_ignore_span(
+            &stmts,
+            &vec![parse_stmt("SELECT c FROM t").unwrap()]
+        ));
+        assert!(matches!(
+            errors.as_slice(),
+            [first, second] if first.cause.ends_with("got SELECT") && first.start == 16
+                && second.cause.ends_with("got SELECT") && second.start == 38
+        ));
+        // the rest of a broken statement is a partial statement
+        let script = parse_script_recovering(
+            "SELECT a b FROM t WHERE c = 1 UNION SELECT d FROM u ORDER BY 1; SELECT e FROM v",
+        );
+        assert!(eq_ignore_span(
+            &script.stmts,
+            &vec![parse_stmt("SELECT e FROM v").unwrap()]
+        ));
+        assert_eq!(script.errors.len(), 1);
+        // only the parts after the error are real, the broken select list is left out
+        let [Stmt::SETOPERATION(partial)] = script.partial_stmts.as_slice() else {
+            panic!("not a set operation");
+        };
+        assert_eq!(
+            partial.to_string(),
+            "FROM t WHERE c = 1 UNION SELECT d FROM u ORDER BY 1"
+        );
+        let QueryStmt::SELECT(left) = partial.left.as_ref() else {
+            panic!("not a select statement");
+        };
+        assert!(left.select_clause.is_none());
+        assert_eq!(left.from_clause.span.range(), 11..17);
+        assert_eq!(left.where_clause.as_ref().unwrap().span.range(), 18..29);
+        assert!(
+            matches!(partial.right.as_ref(), QueryStmt::SELECT(right) if right.select_clause.is_some())
+        );
+        // the rest is checked like a statement
+        for (sql, cause, start) in [
+            (
+                "SELECT 1 2 FROM t LIMIT 1 FETCH FIRST 1 ROWS ONLY",
+                "expected one of OFFSET, ';', got FETCH",
+                26,
+            ),
+            (
+                "SELECT 1 2 FROM t FETCH FIRST 1 ROWS WITH TIES",
+                "WITH TIES requires ORDER BY",
+                18,
+            ),
+            (
+                "SELECT 1 2 FROM t ORDER BY 0",
+                "ORDER BY position 0 is not in select list",
+                27,
+            ),
+        ] {
+            let script = parse_script_recovering(sql);
+            assert!(script.stmts.is_empty());
+            assert!(matches!(
+                script.errors.as_slice(),
+                [_, second] if second.cause == cause && second.start == start
+            ));
+        }
+        // a statement with a lexer error in it is a partial statement
+        // an unterminated string runs to the end
+        let script = parse_script_recovering("select x from t where a = 1 'x; select y from u");
+        assert!(script.stmts.is_empty());
+        assert!(eq_ignore_span(
+            &script.partial_stmts,
+            &vec![parse_stmt("SELECT x FROM t WHERE a = 1").unwrap()]
+        ));
+        assert!(matches!(
+            script.errors.as_slice(),
+            [err] if err.cause == "unterminated string literal" && err.start == 28
+        ));
+        let script = parse_script_recovering("SELECT a FROM t #; SELECT b FROM u");
+        assert!(eq_ignore_span(
+            &script.stmts,
+            &vec![parse_stmt("SELECT b FROM u").unwrap()]
+        ));
+        assert!(eq_ignore_span(
+            &script.partial_stmts,
+            &vec![parse_stmt("SELECT a FROM t").unwrap()]
+        ));
+    }
+}
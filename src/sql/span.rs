@@ -0,0 +1,97 @@
+use std::ops::Range;
+
+/// Byte range of an AST node in the source text
+///
+/// Trees parsed from different places compare unequal, use `eq_ignore_span` to
+/// compare their structure only.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// AST node with a span
+pub trait Spanned {
+    fn span(&self) -> Span;
+}
+
+/// AST node whose spans can be reset, see `eq_ignore_span`
+pub trait ResetSpan {
+    /// Sets the span of the node and of all its children to `Span::default()`
+    fn reset_span(&mut self);
+}
+
+impl<T: ResetSpan> ResetSpan for Option<T> {
+    fn reset_span(&mut self) {
+        if let Some(node) = self {
+            node.reset_span();
+        }
+    }
+}
+
+impl<T: ResetSpan> ResetSpan for Vec<T> {
+    fn reset_span(&mut self) {
+        for node in self {
+            node.reset_span();
+        }
+    }
+}
+
+/// Compares two trees without their spans, e.g. a parsed tree with one built by `new`
+pub fn eq_ignore_span<T: ResetSpan + Clone + PartialEq>(left: &T, right: &T) -> bool {
+    let (mut left, mut right) = (left.clone(), right.clone());
+    left.reset_span();
+    right.reset_span();
+    left == right
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::{
+        expr::{expr::Expr, name::NameExpr},
+        parser::parse_stmt,
+    };
+
+    use super::{eq_ignore_span, Span};
+
+    #[test]
+    fn it_new() {
+        let span = Span::new(3, 7);
+        assert_eq!(span.range(), 3..7);
+        assert_eq!(Span::default().range(), 0..0);
+    }
+
+    #[test]
+    fn it_eq() {
+        assert_eq!(Span::new(3, 7), Span::new(3, 7));
+        assert_ne!(Span::new(3, 7), Span::new(0, 1));
+        let parsed = Expr::NAME(NameExpr {
+            span: Span::new(3, 7),
+            ..NameExpr::new("a")
+        });
+        assert_ne!(parsed, Expr::NAME(NameExpr::new("a")));
+        assert!(eq_ignore_span(&parsed, &Expr::NAME(NameExpr::new("a"))));
+        assert!(!eq_ignore_span(&parsed, &Expr::NAME(NameExpr::new("b"))));
+        // the spans of every node are left out, values are not
+        let stmt = |sql| parse_stmt(sql).unwrap();
+        assert!(eq_ignore_span(
+            &stmt("SELECT a, b FROM t JOIN u USING (c) WHERE d IN (1, 2) ORDER BY 1"),
+            &stmt("select  a ,b from t join u using(c)  where d in(1,2) order by 1"),
+        ));
+        assert!(!eq_ignore_span(
+            &stmt("SELECT 'Span { start: 1, end: 2 }' FROM t"),
+            &stmt("SELECT '' FROM t"),
+        ));
+    }
+}
@@ -1,2 +1,5 @@
+pub mod query;
 pub mod select;
+pub mod set_operation;
+#[allow(clippy::module_inception)]
 pub mod stmt;
@@ -0,0 +1,516 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    clause::{
+        fetch::FetchClause, limit::LimitClause, offset::OffsetClause, order_by::OrderByClause,
+        with::WithClause,
+    },
+    err::SyntaxError,
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
+    parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
+};
+
+use super::{
+    select::SelectStmt,
+    set_operation::{SetOp, SetOperationStmt},
+};
+
+/// Query
+///
+/// A single select or a set operation of queries, `INTERSECT` binds tighter than `UNION`
+/// and `EXCEPT`. `WITH`, `ORDER BY` and `LIMIT` belong to the whole query, a parenthesized
+/// operand may have its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+// most queries are a single select, it is not boxed
+#[allow(clippy::large_enum_variant)]
+pub enum QueryStmt<'a> {
+    SELECT(SelectStmt<'a>),
+    SETOPERATION(SetOperationStmt<'a>),
+}
+
+impl Named for QueryStmt<'_> {
+    const NAMED: &'static str = "query";
+}
+
+impl NamedEnum for QueryStmt<'_> {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::SELECT(_) => SelectStmt::NAMED,
+            Self::SETOPERATION(_) => SetOperationStmt::NAMED,
+        }
+    }
+}
+
+impl Spanned for QueryStmt<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::SELECT(stmt) => stmt.span(),
+            Self::SETOPERATION(stmt) => stmt.span(),
+        }
+    }
+}
+
+impl<'a> LexerParser<'a> for QueryStmt<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        let (with_clause, with_end_index) = match source.items.get(index) {
+            Some(Lexer::WITH(_)) => {
+                let (clause, end_index) = WithClause::parse(source, index)?;
+                (Some(clause), end_index)
+            }
+            _ => (None, index),
+        };
+        let (query, body_end_index) = Self::parse_body(source, with_end_index, 0)?;
+        let (tail, end_index) = QueryTail::parse(source, body_end_index)?;
+        let query = query.attach(source, with_clause, tail, source.span(index, end_index))?;
+        Ok((query, end_index))
+    }
+}
+
+impl<'a> QueryStmt<'a> {
+    /// Binding strength of a set operation, a select binds tightest
+    pub fn precedence(&self) -> usize {
+        match self {
+            Self::SELECT(_) => 3,
+            Self::SETOPERATION(stmt) => stmt.op.precedence(),
+        }
+    }
+
+    /// The leftmost select, it names the columns of the query
+    pub fn first_select(&self) -> &SelectStmt<'a> {
+        match self {
+            Self::SELECT(stmt) => stmt,
+            Self::SETOPERATION(stmt) => stmt.left.first_select(),
+        }
+    }
+
+    /// Writes the query as an operand, in parentheses if it binds weaker than `precedence`
+    /// or it has a clause of its own
+    pub fn to_sql_wrapped(&self, options: &FormatOptions, precedence: usize) -> String {
+        let has_clauses = match self {
+            Self::SELECT(stmt) => {
+                stmt.with_clause.is_some()
+                    || stmt.order_by_clause.is_some()
+                    || stmt.limit_clause.is_some()
+                    || stmt.offset_clause.is_some()
+                    || stmt.fetch_clause.is_some()
+            }
+            Self::SETOPERATION(stmt) => {
+                stmt.with_clause.is_some()
+                    || stmt.order_by_clause.is_some()
+                    || stmt.limit_clause.is_some()
+                    || stmt.offset_clause.is_some()
+                    || stmt.fetch_clause.is_some()
+            }
+        };
+        match has_clauses || self.precedence() < precedence {
+            true => format!("({})", self.to_sql(options)),
+            false => self.to_sql(options),
+        }
+    }
+
+    /// Parses a query from the `FROM` clause of its first select on, the first select has
+    /// no select clause
+    ///
+    /// Error recovery uses it to check the rest of a statement with a broken select list.
+    pub fn parse_from(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        let (select, select_end_index) = SelectStmt::parse_from(source, index, None, index)?;
+        let (query, body_end_index) = Self::parse_set_operations(
+            source,
+            index,
+            QueryStmt::SELECT(select),
+            select_end_index,
+            0,
+        )?;
+        let (tail, end_index) = QueryTail::parse(source, body_end_index)?;
+        let query = query.attach(source, None, tail, source.span(index, end_index))?;
+        Ok((query, end_index))
+    }
+
+    /// operand [{UNION | INTERSECT | EXCEPT} [ALL | DISTINCT] operand ...], the operators
+    /// bind at least as tight as `precedence`
+    fn parse_body(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+        precedence: usize,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        let (query, end_index) = Self::parse_operand(source, index)?;
+        Self::parse_set_operations(source, index, query, end_index, precedence)
+    }
+
+    /// The set operations after the left operand `query`, it starts at `index`
+    fn parse_set_operations(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+        mut query: Self,
+        mut end_index: usize,
+        precedence: usize,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        loop {
+            let op = match source.items.get(end_index) {
+                Some(Lexer::UNION(_)) => SetOp::UNION,
+                Some(Lexer::INTERSECT(_)) => SetOp::INTERSECT,
+                Some(Lexer::EXCEPT(_)) => SetOp::EXCEPT,
+                _ => {
+                    source.expect(end_index, &["UNION", "INTERSECT", "EXCEPT"]);
+                    break;
+                }
+            };
+            if op.precedence() < precedence {
+                break;
+            }
+            let (all, right_index) = match source.items.get(end_index + 1) {
+                Some(Lexer::ALL(_)) => (true, end_index + 2),
+                Some(Lexer::DISTINCT(_)) => (false, end_index + 2),
+                _ => {
+                    source.expect(end_index + 1, &["ALL", "DISTINCT"]);
+                    (false, end_index + 1)
+                }
+            };
+            // left associative, the right side only takes tighter operators
+            let (right, right_end_index) =
+                Self::parse_body(source, right_index, op.precedence() + 1)?;
+            query = QueryStmt::SETOPERATION(SetOperationStmt {
+                all,
+                span: source.span(index, right_end_index),
+                ..SetOperationStmt::new(query, op, right)
+            });
+            end_index = right_end_index;
+        }
+        Ok((query, end_index))
+    }
+
+    /// A select without `ORDER BY` and `LIMIT`, or a parenthesized query
+    fn parse_operand(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
+        match source.items.get(index) {
+            Some(Lexer::SELECT(_)) => match SelectStmt::parse(source, index) {
+                Ok((stmt, end_index)) => Ok((QueryStmt::SELECT(stmt), end_index)),
+                Err(err) => Err(err),
+            },
+            Some(Lexer::LPAREN(_)) => {
+                let (query, end_index) = Self::parse(source, index + 1)?;
+                match source.items.get(end_index) {
+                    Some(Lexer::RPAREN(_)) => Ok((query, end_index + 1)),
+                    _ => Err(source.missing(end_index, ")", &["')'"])),
+                }
+            }
+            _ => Err(source.missing(index, Self::NAMED, &["SELECT", "'('"])),
+        }
+    }
+
+    /// Adds the `WITH` clause and the tail to the query, a parenthesized query may
+    /// already have them, e.g. `(SELECT a FROM t LIMIT 1) LIMIT 2` has two limits
+    fn attach(
+        mut self,
+        source: &SyntaxPattern<'a>,
+        with_clause: Option<WithClause<'a>>,
+        tail: QueryTail<'a>,
+        span: Span,
+    ) -> Result<Self, SyntaxError<'a>> {
+        let order_by_clause = match tail.order_by_clause {
+            Some(clause) => {
+                Some(clause.resolve(source, self.first_select().select_clause.as_ref())?)
+            }
+            None => None,
+        };
+        let (with_slot, order_by_slot, limit_slot, offset_slot, fetch_slot, span_slot) =
+            match &mut self {
+                Self::SELECT(stmt) => (
+                    &mut stmt.with_clause,
+                    &mut stmt.order_by_clause,
+                    &mut stmt.limit_clause,
+                    &mut stmt.offset_clause,
+                    &mut stmt.fetch_clause,
+                    &mut stmt.span,
+                ),
+                Self::SETOPERATION(stmt) => (
+                    &mut stmt.with_clause,
+                    &mut stmt.order_by_clause,
+                    &mut stmt.limit_clause,
+                    &mut stmt.offset_clause,
+                    &mut stmt.fetch_clause,
+                    &mut stmt.span,
+                ),
+            };
+        set_once(
+            source,
+            with_slot,
+            with_clause,
+            "multiple WITH clauses not allowed",
+        )?;
+        set_once(
+            source,
+            order_by_slot,
+            order_by_clause,
+            "multiple ORDER BY clauses not allowed",
+        )?;
+        set_once(
+            source,
+            offset_slot,
+            tail.offset_clause,
+            "multiple OFFSET clauses not allowed",
+        )?;
+        // LIMIT and FETCH are two forms of the same clause
+        let limit_span = match (&tail.limit_clause, &tail.fetch_clause) {
+            (Some(clause), _) => Some(clause.span),
+            (_, Some(clause)) => Some(clause.span),
+            _ => None,
+        };
+        if let Some(limit_span) = limit_span {
+            if limit_slot.is_some() || fetch_slot.is_some() {
+                return Err(source.invalid(limit_span, "multiple LIMIT clauses not allowed"));
+            }
+            *limit_slot = tail.limit_clause;
+            *fetch_slot = tail.fetch_clause;
+        }
+        if let Some(clause) = fetch_slot {
+            if clause.with_ties && order_by_slot.is_none() {
+                return Err(source.invalid(clause.span, "WITH TIES requires ORDER BY"));
+            }
+        }
+        *span_slot = span;
+        Ok(self)
+    }
+}
+
+/// Puts the clause into an empty slot
+fn set_once<'a, T: Spanned>(
+    source: &SyntaxPattern<'a>,
+    slot: &mut Option<T>,
+    clause: Option<T>,
+    cause: &str,
+) -> Result<(), SyntaxError<'a>> {
+    match (&slot, clause) {
+        (Some(_), Some(clause)) => Err(source.invalid(clause.span(), cause)),
+        (None, Some(clause)) => {
+            *slot = Some(clause);
+            Ok(())
+        }
+        (_, None) => Ok(()),
+    }
+}
+
+/// `[ORDER BY ...] [LIMIT n [OFFSET m] | [OFFSET m ROWS] [FETCH FIRST n ROWS ONLY]]`
+struct QueryTail<'a> {
+    order_by_clause: Option<OrderByClause<'a>>,
+    limit_clause: Option<LimitClause<'a>>,
+    offset_clause: Option<OffsetClause<'a>>,
+    fetch_clause: Option<FetchClause<'a>>,
+}
+
+impl<'a> QueryTail<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        let (order_by_clause, order_by_end_index) = match source.items.get(index) {
+            Some(Lexer::ORDER(_)) => {
+                let (clause, end_index) = OrderByClause::parse(source, index)?;
+                (Some(clause), end_index)
+            }
+            _ => {
+                source.expect(index, &["ORDER"]);
+                (None, index)
+            }
+        };
+
+        let (limit_clause, limit_end_index) = match source.items.get(order_by_end_index) {
+            Some(Lexer::LIMIT(_)) => {
+                let (clause, end_index) = LimitClause::parse(source, order_by_end_index)?;
+                (Some(clause), end_index)
+            }
+            _ => {
+                source.expect(order_by_end_index, &["LIMIT"]);
+                (None, order_by_end_index)
+            }
+        };
+
+        let (offset_clause, offset_end_index) = match source.items.get(limit_end_index) {
+            Some(Lexer::OFFSET(_)) => {
+                let (clause, end_index) = OffsetClause::parse(source, limit_end_index)?;
+                (Some(clause), end_index)
+            }
+            _ => {
+                source.expect(limit_end_index, &["OFFSET"]);
+                (None, limit_end_index)
+            }
+        };
+
+        let (fetch_clause, fetch_end_index) = match source.items.get(offset_end_index) {
+            Some(Lexer::FETCH(_)) if limit_clause.is_none() => {
+                let (clause, end_index) = FetchClause::parse(source, offset_end_index)?;
+                (Some(clause), end_index)
+            }
+            _ => {
+                if limit_clause.is_none() {
+                    source.expect(offset_end_index, &["FETCH"]);
+                }
+                (None, offset_end_index)
+            }
+        };
+
+        Ok((
+            QueryTail {
+                order_by_clause,
+                limit_clause,
+                offset_clause,
+                fetch_clause,
+            },
+            fetch_end_index,
+        ))
+    }
+}
+
+/// Writes the `ORDER BY`, `LIMIT`, `OFFSET` and `FETCH` clauses of a query
+pub fn tail_to_sql(
+    options: &FormatOptions,
+    order_by_clause: &Option<OrderByClause>,
+    limit_clause: &Option<LimitClause>,
+    offset_clause: &Option<OffsetClause>,
+    fetch_clause: &Option<FetchClause>,
+) -> Vec<String> {
+    let mut clauses = vec![];
+    if let Some(order_by_clause) = order_by_clause {
+        clauses.push(order_by_clause.to_sql(options));
+    }
+    if let Some(limit_clause) = limit_clause {
+        clauses.push(limit_clause.to_sql(options));
+    }
+    match (offset_clause, fetch_clause) {
+        // the standard form needs ROWS
+        (Some(offset_clause), Some(_)) => clauses.push(format!(
+            "{} {}",
+            offset_clause.to_sql(options),
+            options.keyword("ROWS")
+        )),
+        (Some(offset_clause), None) => clauses.push(offset_clause.to_sql(options)),
+        _ => {}
+    }
+    if let Some(fetch_clause) = fetch_clause {
+        clauses.push(fetch_clause.to_sql(options));
+    }
+    clauses
+}
+
+impl ToSql for QueryStmt<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        match self {
+            Self::SELECT(stmt) => stmt.to_sql(options),
+            Self::SETOPERATION(stmt) => stmt.to_sql(options),
+        }
+    }
+}
+
+impl fmt::Display for QueryStmt<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use crate::sql::{
+        lexer::pattern::LexerPattern,
+        parser::{parse_stmt, LexerParser, SyntaxPattern},
+        span::Spanned,
+        stmt::{set_operation::SetOp, stmt::Stmt},
+    };
+
+    use super::QueryStmt;
+
+    #[test]
+    fn it_name() {
+        assert_eq!(QueryStmt::NAMED, "query");
+    }
+
+    #[test]
+    fn it_parse() {
+        // lexers is end
+        let mut source = SyntaxPattern::new("", vec![]);
+        assert!(matches!(
+            QueryStmt::parse(&source, 0),
+            Err(err) if err.cause == "missing query"
+        ));
+        // single select
+        let text = "SELECT a FROM t ORDER BY a LIMIT 1";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            QueryStmt::parse(&source, 0),
+            Ok((QueryStmt::SELECT(stmt), index)) if stmt.limit_clause.is_some()
+                && stmt.span.range() == (0..34) && index == 9
+        ));
+        // INTERSECT binds tighter, UNION is left associative
+        let text = "SELECT a FROM t UNION SELECT a FROM u INTERSECT SELECT a FROM v UNION ALL SELECT a FROM w";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        let Ok((QueryStmt::SETOPERATION(stmt), 20)) = QueryStmt::parse(&source, 0) else {
+            panic!("not a set operation");
+        };
+        assert!(stmt.op == SetOp::UNION && stmt.all && stmt.span.range() == (0..text.len()));
+        let QueryStmt::SETOPERATION(left) = stmt.left.as_ref() else {
+            panic!("not a set operation");
+        };
+        assert!(left.op == SetOp::UNION && !left.all);
+        assert!(matches!(
+            left.right.as_ref(),
+            QueryStmt::SETOPERATION(right) if right.op == SetOp::INTERSECT
+        ));
+        // the clauses belong to the whole query
+        let text = "(SELECT a AS x FROM t) EXCEPT DISTINCT SELECT b FROM u ORDER BY x OFFSET 2";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            QueryStmt::parse(&source, 0),
+            Ok((QueryStmt::SETOPERATION(stmt), 19)) if !stmt.all
+                && stmt.order_by_clause.is_some() && stmt.offset_clause.is_some()
+                && stmt.left.span().range() == (1..21)
+                && stmt.left.name() == "select statement"
+        ));
+        // missing )
+        let text = "(SELECT a FROM t UNION SELECT a FROM u";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            QueryStmt::parse(&source, 0),
+            Err(err) if err.cause == "missing )"
+        ));
+        // WITH TIES of the whole query
+        let text = "(SELECT a FROM t ORDER BY a) FETCH FIRST 1 ROW WITH TIES";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(QueryStmt::parse(&source, 0).is_ok());
+        let text = "SELECT a FROM t UNION SELECT a FROM u FETCH FIRST 1 ROW WITH TIES";
+        source = SyntaxPattern::new(text, LexerPattern::new(text).matches().unwrap());
+        assert!(matches!(
+            QueryStmt::parse(&source, 0),
+            Err(err) if err.cause == "WITH TIES requires ORDER BY"
+        ));
+    }
+
+    #[test]
+    fn it_parse_stmt() {
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t UNION ALL SELECT b FROM u ORDER BY a LIMIT 1"),
+            Ok(Stmt::SETOPERATION(stmt)) if stmt.all && stmt.limit_clause.is_some()
+        ));
+        assert!(matches!(
+            parse_stmt("(SELECT a FROM t ORDER BY a LIMIT 1) ORDER BY a"),
+            Err(err) if err.cause == "multiple ORDER BY clauses not allowed"
+        ));
+        assert!(matches!(
+            parse_stmt("(SELECT a FROM t LIMIT 1) FETCH FIRST 2 ROWS ONLY"),
+            Err(err) if err.cause == "multiple LIMIT clauses not allowed"
+                && err.start == 26 && err.end == 49
+        ));
+        assert!(matches!(
+            parse_stmt("SELECT a FROM t UNION garbage"),
+            Err(err) if err.cause == "expected one of ALL, DISTINCT, SELECT, '(', got identifier"
+        ));
+    }
+}
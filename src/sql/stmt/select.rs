@@ -1,53 +1,158 @@
+use std::fmt;
+
 use rsdb::Named;
 
 use crate::sql::{
-    clause::{from::FromClause, select::SelectClause},
+    clause::{
+        fetch::FetchClause, from::FromClause, group_by::GroupByClause, having::HavingClause,
+        limit::LimitClause, offset::OffsetClause, order_by::OrderByClause, r#where::WhereClause,
+        select::SelectClause, with::WithClause,
+    },
     err::SyntaxError,
-    lexer::{lexer::Lexer, mat::LexerMatch},
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
     parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
 };
 
+use super::query::tail_to_sql;
+
+/// Select Statement
+///
+/// The parser reads `SELECT ... [HAVING ...]`, the `WITH` clause and the clauses from
+/// `ORDER BY` on belong to the query, see `QueryStmt`, they are set for a single select.
+/// The select clause is only missing in a partial statement of `parse_script_recovering`,
+/// which continues a statement with a broken select list at its `FROM`.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SelectStmt {
-    pub select_clause: SelectClause,
-    pub from_clause: FromClause,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectStmt<'a> {
+    pub with_clause: Option<WithClause<'a>>,
+    pub select_clause: Option<SelectClause<'a>>,
+    pub from_clause: FromClause<'a>,
+    pub where_clause: Option<WhereClause<'a>>,
+    pub group_by_clause: Option<GroupByClause<'a>>,
+    pub having_clause: Option<HavingClause<'a>>,
+    pub order_by_clause: Option<OrderByClause<'a>>,
+    pub limit_clause: Option<LimitClause<'a>>,
+    pub offset_clause: Option<OffsetClause<'a>>,
+    pub fetch_clause: Option<FetchClause<'a>>,
+    pub span: Span,
 }
 
-impl Named for SelectStmt {
+impl Named for SelectStmt<'_> {
     const NAMED: &'static str = "select statement";
 }
 
-impl LexerParser for SelectStmt {
-    fn parse(source: &SyntaxPattern, index: usize) -> Result<(Self, usize), SyntaxError> {
-        let (select_clause, select_end_index) = match SelectClause::parse(source, index) {
-            Ok(clause) => clause,
-            Err(err) => return Err(err),
-        };
+impl Spanned for SelectStmt<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
 
+impl<'a> LexerParser<'a> for SelectStmt<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
+        let (select_clause, select_end_index) = SelectClause::parse(source, index)?;
+        Self::parse_from(source, index, Some(select_clause), select_end_index)
+    }
+}
+
+impl<'a> SelectStmt<'a> {
+    /// Parses `FROM ... [HAVING ...]` after the select clause, the statement starts at `index`
+    pub fn parse_from(
+        source: &SyntaxPattern<'a>,
+        index: usize,
+        select_clause: Option<SelectClause<'a>>,
+        select_end_index: usize,
+    ) -> Result<(Self, usize), SyntaxError<'a>> {
         let (from_clause, from_end_index) = match source.items.get(select_end_index) {
-            Some(lexer) => match lexer {
-                Lexer::FROM(_) => match FromClause::parse(source, select_end_index) {
-                    Ok(clause) => clause,
-                    Err(err) => return Err(err),
-                },
-                _ => {
-                    return Err(SyntaxError::new_missing(lexer.value(), FromClause::NAMED));
-                }
-            },
-            None => {
-                return Err(SyntaxError::new_missing(
-                    LexerMatch::new_eof(&source.text),
-                    FromClause::NAMED,
-                ))
+            Some(Lexer::FROM(_)) => FromClause::parse(source, select_end_index)?,
+            _ => return Err(source.missing(select_end_index, FromClause::NAMED, &["FROM"])),
+        };
+
+        let (where_clause, where_end_index) = match source.items.get(from_end_index) {
+            Some(Lexer::WHERE(_)) => {
+                let (clause, end_index) = WhereClause::parse(source, from_end_index)?;
+                (Some(clause), end_index)
+            }
+            _ => {
+                source.expect(from_end_index, &["WHERE"]);
+                (None, from_end_index)
+            }
+        };
+
+        let (group_by_clause, group_by_end_index) = match source.items.get(where_end_index) {
+            Some(Lexer::GROUP(_)) => {
+                let (clause, end_index) = GroupByClause::parse(source, where_end_index)?;
+                (Some(clause), end_index)
+            }
+            _ => {
+                source.expect(where_end_index, &["GROUP"]);
+                (None, where_end_index)
+            }
+        };
+
+        let (having_clause, having_end_index) = match source.items.get(group_by_end_index) {
+            Some(Lexer::HAVING(_)) => {
+                let (clause, end_index) = HavingClause::parse(source, group_by_end_index)?;
+                (Some(clause), end_index)
+            }
+            _ => {
+                source.expect(group_by_end_index, &["HAVING"]);
+                (None, group_by_end_index)
             }
         };
 
         Ok((
             SelectStmt {
+                with_clause: None,
                 select_clause,
                 from_clause,
+                where_clause,
+                group_by_clause,
+                having_clause,
+                order_by_clause: None,
+                limit_clause: None,
+                offset_clause: None,
+                fetch_clause: None,
+                span: source.span(index, having_end_index),
             },
-            from_end_index,
+            having_end_index,
         ))
     }
 }
+
+impl ToSql for SelectStmt<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let mut clauses = vec![];
+        if let Some(with_clause) = &self.with_clause {
+            clauses.push(with_clause.to_sql(options));
+        }
+        if let Some(select_clause) = &self.select_clause {
+            clauses.push(select_clause.to_sql(options));
+        }
+        clauses.push(self.from_clause.to_sql(options));
+        if let Some(where_clause) = &self.where_clause {
+            clauses.push(where_clause.to_sql(options));
+        }
+        if let Some(group_by_clause) = &self.group_by_clause {
+            clauses.push(group_by_clause.to_sql(options));
+        }
+        if let Some(having_clause) = &self.having_clause {
+            clauses.push(having_clause.to_sql(options));
+        }
+        clauses.extend(tail_to_sql(
+            options,
+            &self.order_by_clause,
+            &self.limit_clause,
+            &self.offset_clause,
+            &self.fetch_clause,
+        ));
+        options.clauses(&clauses)
+    }
+}
+
+impl fmt::Display for SelectStmt<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt;
+
+use rsdb::{Named, NamedEnum};
+
+use crate::sql::{
+    clause::{
+        fetch::FetchClause, limit::LimitClause, offset::OffsetClause, order_by::OrderByClause,
+        with::WithClause,
+    },
+    format::{fmt_sql, FormatOptions, ToSql},
+    span::{Span, Spanned},
+};
+
+use super::query::{tail_to_sql, QueryStmt};
+
+/// Set operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SetOp {
+    UNION,
+    INTERSECT,
+    EXCEPT,
+}
+
+impl SetOp {
+    /// Binding strength, see `QueryStmt::precedence`
+    pub fn precedence(&self) -> usize {
+        match self {
+            Self::UNION | Self::EXCEPT => 1,
+            Self::INTERSECT => 2,
+        }
+    }
+}
+
+impl NamedEnum for SetOp {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::UNION => "UNION",
+            Self::INTERSECT => "INTERSECT",
+            Self::EXCEPT => "EXCEPT",
+        }
+    }
+}
+
+/// Set Operation Statement
+///
+/// `left {UNION | INTERSECT | EXCEPT} [ALL] right`, duplicate rows are removed unless `all`.
+/// The clauses apply to the result of the operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetOperationStmt<'a> {
+    pub with_clause: Option<WithClause<'a>>,
+    pub left: Box<QueryStmt<'a>>,
+    pub op: SetOp,
+    pub all: bool,
+    pub right: Box<QueryStmt<'a>>,
+    pub order_by_clause: Option<OrderByClause<'a>>,
+    pub limit_clause: Option<LimitClause<'a>>,
+    pub offset_clause: Option<OffsetClause<'a>>,
+    pub fetch_clause: Option<FetchClause<'a>>,
+    pub span: Span,
+}
+
+impl Named for SetOperationStmt<'_> {
+    const NAMED: &'static str = "set operation";
+}
+
+impl Spanned for SetOperationStmt<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a> SetOperationStmt<'a> {
+    /// Creates a new set operation without `ALL` and clauses
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// let stmt = SetOperationStmt::new(left, SetOp::UNION, right);
+    /// ```
+    pub fn new(left: QueryStmt<'a>, op: SetOp, right: QueryStmt<'a>) -> Self {
+        SetOperationStmt {
+            with_clause: None,
+            left: Box::new(left),
+            op,
+            all: false,
+            right: Box::new(right),
+            order_by_clause: None,
+            limit_clause: None,
+            offset_clause: None,
+            fetch_clause: None,
+            span: Span::default(),
+        }
+    }
+}
+
+impl ToSql for SetOperationStmt<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let mut clauses = vec![];
+        if let Some(with_clause) = &self.with_clause {
+            clauses.push(with_clause.to_sql(options));
+        }
+        clauses.push(self.left.to_sql_wrapped(options, self.op.precedence()));
+        clauses.push(match self.all {
+            true => format!(
+                "{} {}",
+                options.keyword(self.op.name()),
+                options.keyword("ALL")
+            ),
+            false => options.keyword(self.op.name()),
+        });
+        // left associative, an operation on the right side of the same operator is wrapped
+        clauses.push(self.right.to_sql_wrapped(options, self.op.precedence() + 1));
+        clauses.extend(tail_to_sql(
+            options,
+            &self.order_by_clause,
+            &self.limit_clause,
+            &self.offset_clause,
+            &self.fetch_clause,
+        ));
+        options.clauses(&clauses)
+    }
+}
+
+impl fmt::Display for SetOperationStmt<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rsdb::{Named, NamedEnum};
+
+    use super::{SetOp, SetOperationStmt};
+
+    #[test]
+    fn it_name() {
+        assert_eq!(SetOperationStmt::NAMED, "set operation");
+        assert_eq!(SetOp::INTERSECT.name(), "INTERSECT");
+        assert!(SetOp::INTERSECT.precedence() > SetOp::UNION.precedence());
+        assert_eq!(SetOp::EXCEPT.precedence(), SetOp::UNION.precedence());
+    }
+}
@@ -1,36 +1,74 @@
+use std::fmt;
+
 use rsdb::Named;
 
 use crate::sql::{
     err::SyntaxError,
-    lexer::{lexer::Lexer, mat::LexerMatch},
+    format::{fmt_sql, FormatOptions, ToSql},
+    lexer::lexer::Lexer,
     parser::{LexerParser, SyntaxPattern},
+    span::{Span, Spanned},
 };
 
-use super::select::SelectStmt;
+use super::{query::QueryStmt, select::SelectStmt, set_operation::SetOperationStmt};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Stmt {
-    SELECT(SelectStmt),
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+// most queries are a single select, it is not boxed
+#[allow(clippy::large_enum_variant)]
+pub enum Stmt<'a> {
+    SELECT(SelectStmt<'a>),
+    SETOPERATION(SetOperationStmt<'a>),
 }
 
-impl Named for Stmt {
+impl Named for Stmt<'_> {
     const NAMED: &'static str = "statement";
 }
 
-impl LexerParser for Stmt {
-    fn parse(source: &SyntaxPattern, index: usize) -> Result<(Self, usize), SyntaxError> {
+impl Spanned for Stmt<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::SELECT(stmt) => stmt.span(),
+            Self::SETOPERATION(stmt) => stmt.span(),
+        }
+    }
+}
+
+impl<'a> LexerParser<'a> for Stmt<'a> {
+    fn parse(source: &SyntaxPattern<'a>, index: usize) -> Result<(Self, usize), SyntaxError<'a>> {
         match source.items.get(index) {
-            Some(lexer) => match lexer {
-                Lexer::SELECT(_) => match SelectStmt::parse(source, index) {
-                    Ok((stmt, end_index)) => Ok((Stmt::SELECT(stmt), end_index)),
+            Some(Lexer::SELECT(_) | Lexer::WITH(_) | Lexer::LPAREN(_)) => {
+                match QueryStmt::parse(source, index) {
+                    Ok((query, end_index)) => Ok((Stmt::from(query), end_index)),
                     Err(err) => Err(err),
-                },
-                _ => Err(SyntaxError::new_excpeted(lexer.value())),
-            },
-            None => Err(SyntaxError::new_missing(
-                LexerMatch::new_eof(&source.text),
-                Self::NAMED,
-            )),
+                }
+            }
+            _ => Err(source.missing(index, Self::NAMED, &["SELECT", "WITH"])),
+        }
+    }
+}
+
+impl<'a> From<QueryStmt<'a>> for Stmt<'a> {
+    fn from(query: QueryStmt<'a>) -> Self {
+        match query {
+            QueryStmt::SELECT(stmt) => Stmt::SELECT(stmt),
+            QueryStmt::SETOPERATION(stmt) => Stmt::SETOPERATION(stmt),
+        }
+    }
+}
+
+impl ToSql for Stmt<'_> {
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        match self {
+            Self::SELECT(stmt) => stmt.to_sql(options),
+            Self::SETOPERATION(stmt) => stmt.to_sql(options),
         }
     }
 }
+
+impl fmt::Display for Stmt<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sql(self, f)
+    }
+}
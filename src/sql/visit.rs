@@ -0,0 +1,1358 @@
+use super::{
+    clause::{
+        fetch::FetchClause,
+        from::FromClause,
+        group_by::GroupByClause,
+        having::HavingClause,
+        limit::LimitClause,
+        offset::OffsetClause,
+        order_by::OrderByClause,
+        r#where::WhereClause,
+        select::{SelectClause, SelectQuantifier},
+        with::WithClause,
+    },
+    expr::{
+        aggregate::AggregateExpr, alias::AliasExpr, between::BetweenExpr, binary::BinaryExpr,
+        exists::ExistsExpr, expr::Expr, ident::IdentExpr, in_list::InListExpr,
+        in_subquery::InSubqueryExpr, is_distinct::IsDistinctExpr, is_null::IsNullExpr,
+        items::ItemsExpr, like::LikeExpr, literal::LiteralExpr, name::NameExpr, param::ParamExpr,
+        quantified::QuantifiedExpr, subquery::SubqueryExpr, unary::UnaryExpr,
+    },
+    frag::{
+        cte::Cte,
+        from_item::FromItem,
+        join::{JoinConstraint, JoinExpr},
+        order_by_item::{OrderByItem, OrderByKey},
+        row_count::RowCount,
+        select_item::SelectItem,
+    },
+    span::{ResetSpan, Span},
+    stmt::{query::QueryStmt, select::SelectStmt, set_operation::SetOperationStmt, stmt::Stmt},
+};
+
+/// Walks the AST by reference
+///
+/// Every method visits the children of the node by its `walk_*` function,
+/// overrides call the `walk_*` function to keep walking down.
+pub trait Visitor {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_query(&mut self, stmt: &QueryStmt) {
+        walk_query(self, stmt)
+    }
+
+    fn visit_select_stmt(&mut self, stmt: &SelectStmt) {
+        walk_select_stmt(self, stmt)
+    }
+
+    fn visit_set_operation_stmt(&mut self, stmt: &SetOperationStmt) {
+        walk_set_operation_stmt(self, stmt)
+    }
+
+    fn visit_with_clause(&mut self, clause: &WithClause) {
+        walk_with_clause(self, clause)
+    }
+
+    fn visit_select_clause(&mut self, clause: &SelectClause) {
+        walk_select_clause(self, clause)
+    }
+
+    fn visit_from_clause(&mut self, clause: &FromClause) {
+        walk_from_clause(self, clause)
+    }
+
+    fn visit_where_clause(&mut self, clause: &WhereClause) {
+        walk_where_clause(self, clause)
+    }
+
+    fn visit_group_by_clause(&mut self, clause: &GroupByClause) {
+        walk_group_by_clause(self, clause)
+    }
+
+    fn visit_having_clause(&mut self, clause: &HavingClause) {
+        walk_having_clause(self, clause)
+    }
+
+    fn visit_order_by_clause(&mut self, clause: &OrderByClause) {
+        walk_order_by_clause(self, clause)
+    }
+
+    fn visit_limit_clause(&mut self, clause: &LimitClause) {
+        walk_limit_clause(self, clause)
+    }
+
+    fn visit_offset_clause(&mut self, clause: &OffsetClause) {
+        walk_offset_clause(self, clause)
+    }
+
+    fn visit_fetch_clause(&mut self, clause: &FetchClause) {
+        walk_fetch_clause(self, clause)
+    }
+
+    fn visit_cte(&mut self, cte: &Cte) {
+        walk_cte(self, cte)
+    }
+
+    fn visit_select_alias(&mut self, expr: &AliasExpr<SelectItem>) {
+        walk_select_alias(self, expr)
+    }
+
+    fn visit_from_alias(&mut self, expr: &AliasExpr<FromItem>) {
+        walk_from_alias(self, expr)
+    }
+
+    fn visit_select_item(&mut self, item: &SelectItem) {
+        walk_select_item(self, item)
+    }
+
+    fn visit_from_item(&mut self, item: &FromItem) {
+        walk_from_item(self, item)
+    }
+
+    fn visit_join(&mut self, expr: &JoinExpr) {
+        walk_join(self, expr)
+    }
+
+    fn visit_order_by_item(&mut self, item: &OrderByItem) {
+        walk_order_by_item(self, item)
+    }
+
+    fn visit_row_count(&mut self, count: &RowCount) {
+        walk_row_count(self, count)
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        walk_expr(self, expr)
+    }
+
+    fn visit_unary(&mut self, expr: &UnaryExpr) {
+        walk_unary(self, expr)
+    }
+
+    fn visit_binary(&mut self, expr: &BinaryExpr) {
+        walk_binary(self, expr)
+    }
+
+    fn visit_is_null(&mut self, expr: &IsNullExpr) {
+        walk_is_null(self, expr)
+    }
+
+    fn visit_is_distinct(&mut self, expr: &IsDistinctExpr) {
+        walk_is_distinct(self, expr)
+    }
+
+    fn visit_between(&mut self, expr: &BetweenExpr) {
+        walk_between(self, expr)
+    }
+
+    fn visit_in_list(&mut self, expr: &InListExpr) {
+        walk_in_list(self, expr)
+    }
+
+    fn visit_like(&mut self, expr: &LikeExpr) {
+        walk_like(self, expr)
+    }
+
+    fn visit_aggregate(&mut self, expr: &AggregateExpr) {
+        walk_aggregate(self, expr)
+    }
+
+    fn visit_subquery(&mut self, expr: &SubqueryExpr) {
+        walk_subquery(self, expr)
+    }
+
+    fn visit_exists(&mut self, expr: &ExistsExpr) {
+        walk_exists(self, expr)
+    }
+
+    fn visit_in_subquery(&mut self, expr: &InSubqueryExpr) {
+        walk_in_subquery(self, expr)
+    }
+
+    fn visit_quantified(&mut self, expr: &QuantifiedExpr) {
+        walk_quantified(self, expr)
+    }
+
+    fn visit_name(&mut self, expr: &NameExpr) {
+        walk_name(self, expr)
+    }
+
+    fn visit_ident(&mut self, _expr: &IdentExpr) {}
+
+    fn visit_literal(&mut self, _expr: &LiteralExpr) {}
+
+    fn visit_param(&mut self, _expr: &ParamExpr) {}
+}
+
+pub fn walk_stmt<V: Visitor + ?Sized>(visitor: &mut V, stmt: &Stmt) {
+    match stmt {
+        Stmt::SELECT(stmt) => visitor.visit_select_stmt(stmt),
+        Stmt::SETOPERATION(stmt) => visitor.visit_set_operation_stmt(stmt),
+    }
+}
+
+pub fn walk_query<V: Visitor + ?Sized>(visitor: &mut V, stmt: &QueryStmt) {
+    match stmt {
+        QueryStmt::SELECT(stmt) => visitor.visit_select_stmt(stmt),
+        QueryStmt::SETOPERATION(stmt) => visitor.visit_set_operation_stmt(stmt),
+    }
+}
+
+pub fn walk_select_stmt<V: Visitor + ?Sized>(visitor: &mut V, stmt: &SelectStmt) {
+    if let Some(clause) = &stmt.with_clause {
+        visitor.visit_with_clause(clause);
+    }
+    if let Some(clause) = &stmt.select_clause {
+        visitor.visit_select_clause(clause);
+    }
+    visitor.visit_from_clause(&stmt.from_clause);
+    if let Some(clause) = &stmt.where_clause {
+        visitor.visit_where_clause(clause);
+    }
+    if let Some(clause) = &stmt.group_by_clause {
+        visitor.visit_group_by_clause(clause);
+    }
+    if let Some(clause) = &stmt.having_clause {
+        visitor.visit_having_clause(clause);
+    }
+    if let Some(clause) = &stmt.order_by_clause {
+        visitor.visit_order_by_clause(clause);
+    }
+    if let Some(clause) = &stmt.limit_clause {
+        visitor.visit_limit_clause(clause);
+    }
+    if let Some(clause) = &stmt.offset_clause {
+        visitor.visit_offset_clause(clause);
+    }
+    if let Some(clause) = &stmt.fetch_clause {
+        visitor.visit_fetch_clause(clause);
+    }
+}
+
+pub fn walk_set_operation_stmt<V: Visitor + ?Sized>(visitor: &mut V, stmt: &SetOperationStmt) {
+    if let Some(clause) = &stmt.with_clause {
+        visitor.visit_with_clause(clause);
+    }
+    visitor.visit_query(&stmt.left);
+    visitor.visit_query(&stmt.right);
+    if let Some(clause) = &stmt.order_by_clause {
+        visitor.visit_order_by_clause(clause);
+    }
+    if let Some(clause) = &stmt.limit_clause {
+        visitor.visit_limit_clause(clause);
+    }
+    if let Some(clause) = &stmt.offset_clause {
+        visitor.visit_offset_clause(clause);
+    }
+    if let Some(clause) = &stmt.fetch_clause {
+        visitor.visit_fetch_clause(clause);
+    }
+}
+
+pub fn walk_with_clause<V: Visitor + ?Sized>(visitor: &mut V, clause: &WithClause) {
+    for cte in &clause.ctes.items {
+        visitor.visit_cte(cte);
+    }
+}
+
+pub fn walk_select_clause<V: Visitor + ?Sized>(visitor: &mut V, clause: &SelectClause) {
+    if let Some(SelectQuantifier::DISTINCTON(exprs)) = &clause.quantifier {
+        for expr in &exprs.items {
+            visitor.visit_expr(expr);
+        }
+    }
+    for item in &clause.items.items {
+        visitor.visit_select_alias(item);
+    }
+}
+
+pub fn walk_from_clause<V: Visitor + ?Sized>(visitor: &mut V, clause: &FromClause) {
+    for item in &clause.items.items {
+        visitor.visit_from_alias(item);
+    }
+}
+
+pub fn walk_where_clause<V: Visitor + ?Sized>(visitor: &mut V, clause: &WhereClause) {
+    visitor.visit_expr(&clause.expr);
+}
+
+pub fn walk_group_by_clause<V: Visitor + ?Sized>(visitor: &mut V, clause: &GroupByClause) {
+    for item in &clause.items.items {
+        visitor.visit_expr(item);
+    }
+}
+
+pub fn walk_having_clause<V: Visitor + ?Sized>(visitor: &mut V, clause: &HavingClause) {
+    visitor.visit_expr(&clause.expr);
+}
+
+pub fn walk_order_by_clause<V: Visitor + ?Sized>(visitor: &mut V, clause: &OrderByClause) {
+    for item in &clause.items.items {
+        visitor.visit_order_by_item(item);
+    }
+}
+
+pub fn walk_limit_clause<V: Visitor + ?Sized>(visitor: &mut V, clause: &LimitClause) {
+    visitor.visit_row_count(&clause.count);
+}
+
+pub fn walk_offset_clause<V: Visitor + ?Sized>(visitor: &mut V, clause: &OffsetClause) {
+    visitor.visit_row_count(&clause.count);
+}
+
+pub fn walk_fetch_clause<V: Visitor + ?Sized>(visitor: &mut V, clause: &FetchClause) {
+    if let Some(count) = &clause.count {
+        visitor.visit_row_count(count);
+    }
+}
+
+pub fn walk_cte<V: Visitor + ?Sized>(visitor: &mut V, cte: &Cte) {
+    visitor.visit_ident(&cte.name);
+    if let Some(columns) = &cte.columns {
+        for column in &columns.items {
+            visitor.visit_ident(column);
+        }
+    }
+    visitor.visit_subquery(&cte.query);
+}
+
+pub fn walk_select_alias<V: Visitor + ?Sized>(visitor: &mut V, expr: &AliasExpr<SelectItem>) {
+    visitor.visit_select_item(&expr.value);
+    if let Some(alias) = &expr.alias {
+        visitor.visit_ident(alias);
+    }
+}
+
+pub fn walk_from_alias<V: Visitor + ?Sized>(visitor: &mut V, expr: &AliasExpr<FromItem>) {
+    visitor.visit_from_item(&expr.value);
+    if let Some(alias) = &expr.alias {
+        visitor.visit_ident(alias);
+    }
+}
+
+pub fn walk_select_item<V: Visitor + ?Sized>(visitor: &mut V, item: &SelectItem) {
+    match item {
+        SelectItem::EXPR(expr) => visitor.visit_expr(expr),
+        SelectItem::QUALIFIEDSTAR(expr, _) => visitor.visit_name(expr),
+        SelectItem::STAR(_) => {}
+    }
+}
+
+pub fn walk_from_item<V: Visitor + ?Sized>(visitor: &mut V, item: &FromItem) {
+    match item {
+        FromItem::NAME(expr) => visitor.visit_name(expr),
+        FromItem::JOIN(expr) => visitor.visit_join(expr),
+        FromItem::SUBQUERY(expr) => visitor.visit_subquery(expr),
+    }
+}
+
+pub fn walk_join<V: Visitor + ?Sized>(visitor: &mut V, expr: &JoinExpr) {
+    visitor.visit_from_alias(&expr.left);
+    visitor.visit_from_alias(&expr.right);
+    match &expr.constraint {
+        Some(JoinConstraint::ON(expr)) => visitor.visit_expr(expr),
+        Some(JoinConstraint::USING(items)) => {
+            for item in &items.items {
+                visitor.visit_ident(item);
+            }
+        }
+        None => {}
+    }
+}
+
+pub fn walk_order_by_item<V: Visitor + ?Sized>(visitor: &mut V, item: &OrderByItem) {
+    match &item.key {
+        OrderByKey::EXPR(expr) => visitor.visit_expr(expr),
+        OrderByKey::ALIAS(ident) => visitor.visit_ident(ident),
+        OrderByKey::ORDINAL(..) => {}
+    }
+}
+
+pub fn walk_row_count<V: Visitor + ?Sized>(visitor: &mut V, count: &RowCount) {
+    match count {
+        RowCount::LITERAL(expr) => visitor.visit_literal(expr),
+        RowCount::PARAM(expr) => visitor.visit_param(expr),
+    }
+}
+
+pub fn walk_expr<V: Visitor + ?Sized>(visitor: &mut V, expr: &Expr) {
+    match expr {
+        Expr::NAME(expr) => visitor.visit_name(expr),
+        Expr::LITERAL(expr) => visitor.visit_literal(expr),
+        Expr::PARAM(expr) => visitor.visit_param(expr),
+        Expr::AGGREGATE(expr) => visitor.visit_aggregate(expr),
+        Expr::UNARY(expr) => visitor.visit_unary(expr),
+        Expr::BINARY(expr) => visitor.visit_binary(expr),
+        Expr::ISNULL(expr) => visitor.visit_is_null(expr),
+        Expr::ISDISTINCT(expr) => visitor.visit_is_distinct(expr),
+        Expr::BETWEEN(expr) => visitor.visit_between(expr),
+        Expr::INLIST(expr) => visitor.visit_in_list(expr),
+        Expr::LIKE(expr) => visitor.visit_like(expr),
+        Expr::SUBQUERY(expr) => visitor.visit_subquery(expr),
+        Expr::EXISTS(expr) => visitor.visit_exists(expr),
+        Expr::INSUBQUERY(expr) => visitor.visit_in_subquery(expr),
+        Expr::QUANTIFIED(expr) => visitor.visit_quantified(expr),
+    }
+}
+
+pub fn walk_unary<V: Visitor + ?Sized>(visitor: &mut V, expr: &UnaryExpr) {
+    visitor.visit_expr(&expr.value);
+}
+
+pub fn walk_binary<V: Visitor + ?Sized>(visitor: &mut V, expr: &BinaryExpr) {
+    visitor.visit_expr(&expr.left);
+    visitor.visit_expr(&expr.right);
+}
+
+pub fn walk_is_null<V: Visitor + ?Sized>(visitor: &mut V, expr: &IsNullExpr) {
+    visitor.visit_expr(&expr.value);
+}
+
+pub fn walk_is_distinct<V: Visitor + ?Sized>(visitor: &mut V, expr: &IsDistinctExpr) {
+    visitor.visit_expr(&expr.left);
+    visitor.visit_expr(&expr.right);
+}
+
+pub fn walk_between<V: Visitor + ?Sized>(visitor: &mut V, expr: &BetweenExpr) {
+    visitor.visit_expr(&expr.value);
+    visitor.visit_expr(&expr.low);
+    visitor.visit_expr(&expr.high);
+}
+
+pub fn walk_in_list<V: Visitor + ?Sized>(visitor: &mut V, expr: &InListExpr) {
+    visitor.visit_expr(&expr.value);
+    for item in &expr.list.items {
+        visitor.visit_expr(item);
+    }
+}
+
+pub fn walk_like<V: Visitor + ?Sized>(visitor: &mut V, expr: &LikeExpr) {
+    visitor.visit_expr(&expr.value);
+    visitor.visit_expr(&expr.pattern);
+}
+
+pub fn walk_aggregate<V: Visitor + ?Sized>(visitor: &mut V, expr: &AggregateExpr) {
+    if let Some(arg) = &expr.arg {
+        visitor.visit_expr(arg);
+    }
+}
+
+pub fn walk_subquery<V: Visitor + ?Sized>(visitor: &mut V, expr: &SubqueryExpr) {
+    visitor.visit_query(&expr.stmt);
+}
+
+pub fn walk_exists<V: Visitor + ?Sized>(visitor: &mut V, expr: &ExistsExpr) {
+    visitor.visit_subquery(&expr.subquery);
+}
+
+pub fn walk_in_subquery<V: Visitor + ?Sized>(visitor: &mut V, expr: &InSubqueryExpr) {
+    visitor.visit_expr(&expr.value);
+    visitor.visit_subquery(&expr.subquery);
+}
+
+pub fn walk_quantified<V: Visitor + ?Sized>(visitor: &mut V, expr: &QuantifiedExpr) {
+    visitor.visit_expr(&expr.left);
+    visitor.visit_subquery(&expr.subquery);
+}
+
+pub fn walk_name<V: Visitor + ?Sized>(visitor: &mut V, expr: &NameExpr) {
+    for part in &expr.parts {
+        visitor.visit_ident(part);
+    }
+}
+
+/// Walks the AST by mutable reference, e.g. to rewrite it
+///
+/// Works like `Visitor`, the `walk_*_mut` functions visit the children.
+pub trait VisitorMut {
+    fn visit_stmt_mut(&mut self, stmt: &mut Stmt) {
+        walk_stmt_mut(self, stmt)
+    }
+
+    fn visit_query_mut(&mut self, stmt: &mut QueryStmt) {
+        walk_query_mut(self, stmt)
+    }
+
+    fn visit_select_stmt_mut(&mut self, stmt: &mut SelectStmt) {
+        walk_select_stmt_mut(self, stmt)
+    }
+
+    fn visit_set_operation_stmt_mut(&mut self, stmt: &mut SetOperationStmt) {
+        walk_set_operation_stmt_mut(self, stmt)
+    }
+
+    fn visit_with_clause_mut(&mut self, clause: &mut WithClause) {
+        walk_with_clause_mut(self, clause)
+    }
+
+    fn visit_select_clause_mut(&mut self, clause: &mut SelectClause) {
+        walk_select_clause_mut(self, clause)
+    }
+
+    fn visit_from_clause_mut(&mut self, clause: &mut FromClause) {
+        walk_from_clause_mut(self, clause)
+    }
+
+    fn visit_where_clause_mut(&mut self, clause: &mut WhereClause) {
+        walk_where_clause_mut(self, clause)
+    }
+
+    fn visit_group_by_clause_mut(&mut self, clause: &mut GroupByClause) {
+        walk_group_by_clause_mut(self, clause)
+    }
+
+    fn visit_having_clause_mut(&mut self, clause: &mut HavingClause) {
+        walk_having_clause_mut(self, clause)
+    }
+
+    fn visit_order_by_clause_mut(&mut self, clause: &mut OrderByClause) {
+        walk_order_by_clause_mut(self, clause)
+    }
+
+    fn visit_limit_clause_mut(&mut self, clause: &mut LimitClause) {
+        walk_limit_clause_mut(self, clause)
+    }
+
+    fn visit_offset_clause_mut(&mut self, clause: &mut OffsetClause) {
+        walk_offset_clause_mut(self, clause)
+    }
+
+    fn visit_fetch_clause_mut(&mut self, clause: &mut FetchClause) {
+        walk_fetch_clause_mut(self, clause)
+    }
+
+    fn visit_cte_mut(&mut self, cte: &mut Cte) {
+        walk_cte_mut(self, cte)
+    }
+
+    fn visit_select_alias_mut(&mut self, expr: &mut AliasExpr<SelectItem>) {
+        walk_select_alias_mut(self, expr)
+    }
+
+    fn visit_from_alias_mut(&mut self, expr: &mut AliasExpr<FromItem>) {
+        walk_from_alias_mut(self, expr)
+    }
+
+    fn visit_select_item_mut(&mut self, item: &mut SelectItem) {
+        walk_select_item_mut(self, item)
+    }
+
+    fn visit_from_item_mut(&mut self, item: &mut FromItem) {
+        walk_from_item_mut(self, item)
+    }
+
+    fn visit_join_mut(&mut self, expr: &mut JoinExpr) {
+        walk_join_mut(self, expr)
+    }
+
+    fn visit_order_by_item_mut(&mut self, item: &mut OrderByItem) {
+        walk_order_by_item_mut(self, item)
+    }
+
+    fn visit_row_count_mut(&mut self, count: &mut RowCount) {
+        walk_row_count_mut(self, count)
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        walk_expr_mut(self, expr)
+    }
+
+    fn visit_unary_mut(&mut self, expr: &mut UnaryExpr) {
+        walk_unary_mut(self, expr)
+    }
+
+    fn visit_binary_mut(&mut self, expr: &mut BinaryExpr) {
+        walk_binary_mut(self, expr)
+    }
+
+    fn visit_is_null_mut(&mut self, expr: &mut IsNullExpr) {
+        walk_is_null_mut(self, expr)
+    }
+
+    fn visit_is_distinct_mut(&mut self, expr: &mut IsDistinctExpr) {
+        walk_is_distinct_mut(self, expr)
+    }
+
+    fn visit_between_mut(&mut self, expr: &mut BetweenExpr) {
+        walk_between_mut(self, expr)
+    }
+
+    fn visit_in_list_mut(&mut self, expr: &mut InListExpr) {
+        walk_in_list_mut(self, expr)
+    }
+
+    fn visit_like_mut(&mut self, expr: &mut LikeExpr) {
+        walk_like_mut(self, expr)
+    }
+
+    fn visit_aggregate_mut(&mut self, expr: &mut AggregateExpr) {
+        walk_aggregate_mut(self, expr)
+    }
+
+    fn visit_subquery_mut(&mut self, expr: &mut SubqueryExpr) {
+        walk_subquery_mut(self, expr)
+    }
+
+    fn visit_exists_mut(&mut self, expr: &mut ExistsExpr) {
+        walk_exists_mut(self, expr)
+    }
+
+    fn visit_in_subquery_mut(&mut self, expr: &mut InSubqueryExpr) {
+        walk_in_subquery_mut(self, expr)
+    }
+
+    fn visit_quantified_mut(&mut self, expr: &mut QuantifiedExpr) {
+        walk_quantified_mut(self, expr)
+    }
+
+    fn visit_name_mut(&mut self, expr: &mut NameExpr) {
+        walk_name_mut(self, expr)
+    }
+
+    fn visit_ident_mut(&mut self, _expr: &mut IdentExpr) {}
+
+    fn visit_literal_mut(&mut self, _expr: &mut LiteralExpr) {}
+
+    fn visit_param_mut(&mut self, _expr: &mut ParamExpr) {}
+}
+
+pub fn walk_stmt_mut<V: VisitorMut + ?Sized>(visitor: &mut V, stmt: &mut Stmt) {
+    match stmt {
+        Stmt::SELECT(stmt) => visitor.visit_select_stmt_mut(stmt),
+        Stmt::SETOPERATION(stmt) => visitor.visit_set_operation_stmt_mut(stmt),
+    }
+}
+
+pub fn walk_query_mut<V: VisitorMut + ?Sized>(visitor: &mut V, stmt: &mut QueryStmt) {
+    match stmt {
+        QueryStmt::SELECT(stmt) => visitor.visit_select_stmt_mut(stmt),
+        QueryStmt::SETOPERATION(stmt) => visitor.visit_set_operation_stmt_mut(stmt),
+    }
+}
+
+pub fn walk_select_stmt_mut<V: VisitorMut + ?Sized>(visitor: &mut V, stmt: &mut SelectStmt) {
+    if let Some(clause) = &mut stmt.with_clause {
+        visitor.visit_with_clause_mut(clause);
+    }
+    if let Some(clause) = &mut stmt.select_clause {
+        visitor.visit_select_clause_mut(clause);
+    }
+    visitor.visit_from_clause_mut(&mut stmt.from_clause);
+    if let Some(clause) = &mut stmt.where_clause {
+        visitor.visit_where_clause_mut(clause);
+    }
+    if let Some(clause) = &mut stmt.group_by_clause {
+        visitor.visit_group_by_clause_mut(clause);
+    }
+    if let Some(clause) = &mut stmt.having_clause {
+        visitor.visit_having_clause_mut(clause);
+    }
+    if let Some(clause) = &mut stmt.order_by_clause {
+        visitor.visit_order_by_clause_mut(clause);
+    }
+    if let Some(clause) = &mut stmt.limit_clause {
+        visitor.visit_limit_clause_mut(clause);
+    }
+    if let Some(clause) = &mut stmt.offset_clause {
+        visitor.visit_offset_clause_mut(clause);
+    }
+    if let Some(clause) = &mut stmt.fetch_clause {
+        visitor.visit_fetch_clause_mut(clause);
+    }
+}
+
+pub fn walk_set_operation_stmt_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    stmt: &mut SetOperationStmt,
+) {
+    if let Some(clause) = &mut stmt.with_clause {
+        visitor.visit_with_clause_mut(clause);
+    }
+    visitor.visit_query_mut(&mut stmt.left);
+    visitor.visit_query_mut(&mut stmt.right);
+    if let Some(clause) = &mut stmt.order_by_clause {
+        visitor.visit_order_by_clause_mut(clause);
+    }
+    if let Some(clause) = &mut stmt.limit_clause {
+        visitor.visit_limit_clause_mut(clause);
+    }
+    if let Some(clause) = &mut stmt.offset_clause {
+        visitor.visit_offset_clause_mut(clause);
+    }
+    if let Some(clause) = &mut stmt.fetch_clause {
+        visitor.visit_fetch_clause_mut(clause);
+    }
+}
+
+pub fn walk_with_clause_mut<V: VisitorMut + ?Sized>(visitor: &mut V, clause: &mut WithClause) {
+    for cte in &mut clause.ctes.items {
+        visitor.visit_cte_mut(cte);
+    }
+}
+
+pub fn walk_select_clause_mut<V: VisitorMut + ?Sized>(visitor: &mut V, clause: &mut SelectClause) {
+    if let Some(SelectQuantifier::DISTINCTON(exprs)) = &mut clause.quantifier {
+        for expr in &mut exprs.items {
+            visitor.visit_expr_mut(expr);
+        }
+    }
+    for item in &mut clause.items.items {
+        visitor.visit_select_alias_mut(item);
+    }
+}
+
+pub fn walk_from_clause_mut<V: VisitorMut + ?Sized>(visitor: &mut V, clause: &mut FromClause) {
+    for item in &mut clause.items.items {
+        visitor.visit_from_alias_mut(item);
+    }
+}
+
+pub fn walk_where_clause_mut<V: VisitorMut + ?Sized>(visitor: &mut V, clause: &mut WhereClause) {
+    visitor.visit_expr_mut(&mut clause.expr);
+}
+
+pub fn walk_group_by_clause_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    clause: &mut GroupByClause,
+) {
+    for item in &mut clause.items.items {
+        visitor.visit_expr_mut(item);
+    }
+}
+
+pub fn walk_having_clause_mut<V: VisitorMut + ?Sized>(visitor: &mut V, clause: &mut HavingClause) {
+    visitor.visit_expr_mut(&mut clause.expr);
+}
+
+pub fn walk_order_by_clause_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    clause: &mut OrderByClause,
+) {
+    for item in &mut clause.items.items {
+        visitor.visit_order_by_item_mut(item);
+    }
+}
+
+pub fn walk_limit_clause_mut<V: VisitorMut + ?Sized>(visitor: &mut V, clause: &mut LimitClause) {
+    visitor.visit_row_count_mut(&mut clause.count);
+}
+
+pub fn walk_offset_clause_mut<V: VisitorMut + ?Sized>(visitor: &mut V, clause: &mut OffsetClause) {
+    visitor.visit_row_count_mut(&mut clause.count);
+}
+
+pub fn walk_fetch_clause_mut<V: VisitorMut + ?Sized>(visitor: &mut V, clause: &mut FetchClause) {
+    if let Some(count) = &mut clause.count {
+        visitor.visit_row_count_mut(count);
+    }
+}
+
+pub fn walk_cte_mut<V: VisitorMut + ?Sized>(visitor: &mut V, cte: &mut Cte) {
+    visitor.visit_ident_mut(&mut cte.name);
+    if let Some(columns) = &mut cte.columns {
+        for column in &mut columns.items {
+            visitor.visit_ident_mut(column);
+        }
+    }
+    visitor.visit_subquery_mut(&mut cte.query);
+}
+
+pub fn walk_select_alias_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    expr: &mut AliasExpr<SelectItem>,
+) {
+    visitor.visit_select_item_mut(&mut expr.value);
+    if let Some(alias) = &mut expr.alias {
+        visitor.visit_ident_mut(alias);
+    }
+}
+
+pub fn walk_from_alias_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    expr: &mut AliasExpr<FromItem>,
+) {
+    visitor.visit_from_item_mut(&mut expr.value);
+    if let Some(alias) = &mut expr.alias {
+        visitor.visit_ident_mut(alias);
+    }
+}
+
+pub fn walk_select_item_mut<V: VisitorMut + ?Sized>(visitor: &mut V, item: &mut SelectItem) {
+    match item {
+        SelectItem::EXPR(expr) => visitor.visit_expr_mut(expr),
+        SelectItem::QUALIFIEDSTAR(expr, _) => visitor.visit_name_mut(expr),
+        SelectItem::STAR(_) => {}
+    }
+}
+
+pub fn walk_from_item_mut<V: VisitorMut + ?Sized>(visitor: &mut V, item: &mut FromItem) {
+    match item {
+        FromItem::NAME(expr) => visitor.visit_name_mut(expr),
+        FromItem::JOIN(expr) => visitor.visit_join_mut(expr),
+        FromItem::SUBQUERY(expr) => visitor.visit_subquery_mut(expr),
+    }
+}
+
+pub fn walk_join_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut JoinExpr) {
+    visitor.visit_from_alias_mut(&mut expr.left);
+    visitor.visit_from_alias_mut(&mut expr.right);
+    match &mut expr.constraint {
+        Some(JoinConstraint::ON(expr)) => visitor.visit_expr_mut(expr),
+        Some(JoinConstraint::USING(items)) => {
+            for item in &mut items.items {
+                visitor.visit_ident_mut(item);
+            }
+        }
+        None => {}
+    }
+}
+
+pub fn walk_order_by_item_mut<V: VisitorMut + ?Sized>(visitor: &mut V, item: &mut OrderByItem) {
+    match &mut item.key {
+        OrderByKey::EXPR(expr) => visitor.visit_expr_mut(expr),
+        OrderByKey::ALIAS(ident) => visitor.visit_ident_mut(ident),
+        OrderByKey::ORDINAL(..) => {}
+    }
+}
+
+pub fn walk_row_count_mut<V: VisitorMut + ?Sized>(visitor: &mut V, count: &mut RowCount) {
+    match count {
+        RowCount::LITERAL(expr) => visitor.visit_literal_mut(expr),
+        RowCount::PARAM(expr) => visitor.visit_param_mut(expr),
+    }
+}
+
+pub fn walk_expr_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut Expr) {
+    match expr {
+        Expr::NAME(expr) => visitor.visit_name_mut(expr),
+        Expr::LITERAL(expr) => visitor.visit_literal_mut(expr),
+        Expr::PARAM(expr) => visitor.visit_param_mut(expr),
+        Expr::AGGREGATE(expr) => visitor.visit_aggregate_mut(expr),
+        Expr::UNARY(expr) => visitor.visit_unary_mut(expr),
+        Expr::BINARY(expr) => visitor.visit_binary_mut(expr),
+        Expr::ISNULL(expr) => visitor.visit_is_null_mut(expr),
+        Expr::ISDISTINCT(expr) => visitor.visit_is_distinct_mut(expr),
+        Expr::BETWEEN(expr) => visitor.visit_between_mut(expr),
+        Expr::INLIST(expr) => visitor.visit_in_list_mut(expr),
+        Expr::LIKE(expr) => visitor.visit_like_mut(expr),
+        Expr::SUBQUERY(expr) => visitor.visit_subquery_mut(expr),
+        Expr::EXISTS(expr) => visitor.visit_exists_mut(expr),
+        Expr::INSUBQUERY(expr) => visitor.visit_in_subquery_mut(expr),
+        Expr::QUANTIFIED(expr) => visitor.visit_quantified_mut(expr),
+    }
+}
+
+pub fn walk_unary_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut UnaryExpr) {
+    visitor.visit_expr_mut(&mut expr.value);
+}
+
+pub fn walk_binary_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut BinaryExpr) {
+    visitor.visit_expr_mut(&mut expr.left);
+    visitor.visit_expr_mut(&mut expr.right);
+}
+
+pub fn walk_is_null_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut IsNullExpr) {
+    visitor.visit_expr_mut(&mut expr.value);
+}
+
+pub fn walk_is_distinct_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut IsDistinctExpr) {
+    visitor.visit_expr_mut(&mut expr.left);
+    visitor.visit_expr_mut(&mut expr.right);
+}
+
+pub fn walk_between_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut BetweenExpr) {
+    visitor.visit_expr_mut(&mut expr.value);
+    visitor.visit_expr_mut(&mut expr.low);
+    visitor.visit_expr_mut(&mut expr.high);
+}
+
+pub fn walk_in_list_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut InListExpr) {
+    visitor.visit_expr_mut(&mut expr.value);
+    for item in &mut expr.list.items {
+        visitor.visit_expr_mut(item);
+    }
+}
+
+pub fn walk_like_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut LikeExpr) {
+    visitor.visit_expr_mut(&mut expr.value);
+    visitor.visit_expr_mut(&mut expr.pattern);
+}
+
+pub fn walk_aggregate_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut AggregateExpr) {
+    if let Some(arg) = &mut expr.arg {
+        visitor.visit_expr_mut(arg);
+    }
+}
+
+pub fn walk_subquery_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut SubqueryExpr) {
+    visitor.visit_query_mut(&mut expr.stmt);
+}
+
+pub fn walk_exists_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut ExistsExpr) {
+    visitor.visit_subquery_mut(&mut expr.subquery);
+}
+
+pub fn walk_in_subquery_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut InSubqueryExpr) {
+    visitor.visit_expr_mut(&mut expr.value);
+    visitor.visit_subquery_mut(&mut expr.subquery);
+}
+
+pub fn walk_quantified_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut QuantifiedExpr) {
+    visitor.visit_expr_mut(&mut expr.left);
+    visitor.visit_subquery_mut(&mut expr.subquery);
+}
+
+pub fn walk_name_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut NameExpr) {
+    for part in &mut expr.parts {
+        visitor.visit_ident_mut(part);
+    }
+}
+
+/// Resets every span of the tree, the spans of the item lists included
+struct SpanReset;
+
+impl VisitorMut for SpanReset {
+    fn visit_select_stmt_mut(&mut self, stmt: &mut SelectStmt) {
+        stmt.span = Span::default();
+        walk_select_stmt_mut(self, stmt);
+    }
+
+    fn visit_set_operation_stmt_mut(&mut self, stmt: &mut SetOperationStmt) {
+        stmt.span = Span::default();
+        walk_set_operation_stmt_mut(self, stmt);
+    }
+
+    fn visit_with_clause_mut(&mut self, clause: &mut WithClause) {
+        clause.span = Span::default();
+        clause.ctes.span = Span::default();
+        walk_with_clause_mut(self, clause);
+    }
+
+    fn visit_select_clause_mut(&mut self, clause: &mut SelectClause) {
+        clause.span = Span::default();
+        clause.items.span = Span::default();
+        if let Some(SelectQuantifier::DISTINCTON(exprs)) = &mut clause.quantifier {
+            exprs.span = Span::default();
+        }
+        walk_select_clause_mut(self, clause);
+    }
+
+    fn visit_from_clause_mut(&mut self, clause: &mut FromClause) {
+        clause.span = Span::default();
+        clause.items.span = Span::default();
+        walk_from_clause_mut(self, clause);
+    }
+
+    fn visit_where_clause_mut(&mut self, clause: &mut WhereClause) {
+        clause.span = Span::default();
+        walk_where_clause_mut(self, clause);
+    }
+
+    fn visit_group_by_clause_mut(&mut self, clause: &mut GroupByClause) {
+        clause.span = Span::default();
+        clause.items.span = Span::default();
+        walk_group_by_clause_mut(self, clause);
+    }
+
+    fn visit_having_clause_mut(&mut self, clause: &mut HavingClause) {
+        clause.span = Span::default();
+        walk_having_clause_mut(self, clause);
+    }
+
+    fn visit_order_by_clause_mut(&mut self, clause: &mut OrderByClause) {
+        clause.span = Span::default();
+        clause.items.span = Span::default();
+        walk_order_by_clause_mut(self, clause);
+    }
+
+    fn visit_limit_clause_mut(&mut self, clause: &mut LimitClause) {
+        clause.span = Span::default();
+        walk_limit_clause_mut(self, clause);
+    }
+
+    fn visit_offset_clause_mut(&mut self, clause: &mut OffsetClause) {
+        clause.span = Span::default();
+        walk_offset_clause_mut(self, clause);
+    }
+
+    fn visit_fetch_clause_mut(&mut self, clause: &mut FetchClause) {
+        clause.span = Span::default();
+        walk_fetch_clause_mut(self, clause);
+    }
+
+    fn visit_cte_mut(&mut self, cte: &mut Cte) {
+        cte.span = Span::default();
+        if let Some(columns) = &mut cte.columns {
+            columns.span = Span::default();
+        }
+        walk_cte_mut(self, cte);
+    }
+
+    fn visit_select_alias_mut(&mut self, expr: &mut AliasExpr<SelectItem>) {
+        expr.span = Span::default();
+        walk_select_alias_mut(self, expr);
+    }
+
+    fn visit_from_alias_mut(&mut self, expr: &mut AliasExpr<FromItem>) {
+        expr.span = Span::default();
+        walk_from_alias_mut(self, expr);
+    }
+
+    fn visit_select_item_mut(&mut self, item: &mut SelectItem) {
+        match item {
+            SelectItem::STAR(span) | SelectItem::QUALIFIEDSTAR(_, span) => *span = Span::default(),
+            SelectItem::EXPR(_) => {}
+        }
+        walk_select_item_mut(self, item);
+    }
+
+    fn visit_join_mut(&mut self, expr: &mut JoinExpr) {
+        expr.span = Span::default();
+        if let Some(JoinConstraint::USING(items)) = &mut expr.constraint {
+            items.span = Span::default();
+        }
+        walk_join_mut(self, expr);
+    }
+
+    fn visit_order_by_item_mut(&mut self, item: &mut OrderByItem) {
+        item.span = Span::default();
+        if let OrderByKey::ORDINAL(_, span) = &mut item.key {
+            *span = Span::default();
+        }
+        walk_order_by_item_mut(self, item);
+    }
+
+    fn visit_unary_mut(&mut self, expr: &mut UnaryExpr) {
+        expr.span = Span::default();
+        walk_unary_mut(self, expr);
+    }
+
+    fn visit_binary_mut(&mut self, expr: &mut BinaryExpr) {
+        expr.span = Span::default();
+        walk_binary_mut(self, expr);
+    }
+
+    fn visit_is_null_mut(&mut self, expr: &mut IsNullExpr) {
+        expr.span = Span::default();
+        walk_is_null_mut(self, expr);
+    }
+
+    fn visit_is_distinct_mut(&mut self, expr: &mut IsDistinctExpr) {
+        expr.span = Span::default();
+        walk_is_distinct_mut(self, expr);
+    }
+
+    fn visit_between_mut(&mut self, expr: &mut BetweenExpr) {
+        expr.span = Span::default();
+        walk_between_mut(self, expr);
+    }
+
+    fn visit_in_list_mut(&mut self, expr: &mut InListExpr) {
+        expr.span = Span::default();
+        expr.list.span = Span::default();
+        walk_in_list_mut(self, expr);
+    }
+
+    fn visit_like_mut(&mut self, expr: &mut LikeExpr) {
+        expr.span = Span::default();
+        walk_like_mut(self, expr);
+    }
+
+    fn visit_aggregate_mut(&mut self, expr: &mut AggregateExpr) {
+        expr.span = Span::default();
+        walk_aggregate_mut(self, expr);
+    }
+
+    fn visit_subquery_mut(&mut self, expr: &mut SubqueryExpr) {
+        expr.span = Span::default();
+        walk_subquery_mut(self, expr);
+    }
+
+    fn visit_exists_mut(&mut self, expr: &mut ExistsExpr) {
+        expr.span = Span::default();
+        walk_exists_mut(self, expr);
+    }
+
+    fn visit_in_subquery_mut(&mut self, expr: &mut InSubqueryExpr) {
+        expr.span = Span::default();
+        walk_in_subquery_mut(self, expr);
+    }
+
+    fn visit_quantified_mut(&mut self, expr: &mut QuantifiedExpr) {
+        expr.span = Span::default();
+        walk_quantified_mut(self, expr);
+    }
+
+    fn visit_name_mut(&mut self, expr: &mut NameExpr) {
+        expr.span = Span::default();
+        walk_name_mut(self, expr);
+    }
+
+    fn visit_ident_mut(&mut self, expr: &mut IdentExpr) {
+        expr.span = Span::default();
+    }
+
+    fn visit_literal_mut(&mut self, expr: &mut LiteralExpr) {
+        match expr {
+            LiteralExpr::INTEGER(_, span)
+            | LiteralExpr::DECIMAL(_, span)
+            | LiteralExpr::SCIENTIFIC(_, span)
+            | LiteralExpr::HEX(_, span)
+            | LiteralExpr::STRING(_, span) => *span = Span::default(),
+        }
+    }
+
+    fn visit_param_mut(&mut self, expr: &mut ParamExpr) {
+        expr.span = Span::default();
+    }
+}
+
+/// `ResetSpan` of a node, by the method `SpanReset` visits it with
+macro_rules! impl_reset_span {
+    ($($node:ty => $visit:ident),* $(,)?) => {
+        $(
+            impl ResetSpan for $node {
+                fn reset_span(&mut self) {
+                    SpanReset.$visit(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_reset_span!(
+    Stmt<'_> => visit_stmt_mut,
+    QueryStmt<'_> => visit_query_mut,
+    SelectStmt<'_> => visit_select_stmt_mut,
+    SetOperationStmt<'_> => visit_set_operation_stmt_mut,
+    WithClause<'_> => visit_with_clause_mut,
+    SelectClause<'_> => visit_select_clause_mut,
+    FromClause<'_> => visit_from_clause_mut,
+    WhereClause<'_> => visit_where_clause_mut,
+    GroupByClause<'_> => visit_group_by_clause_mut,
+    HavingClause<'_> => visit_having_clause_mut,
+    OrderByClause<'_> => visit_order_by_clause_mut,
+    LimitClause<'_> => visit_limit_clause_mut,
+    OffsetClause<'_> => visit_offset_clause_mut,
+    FetchClause<'_> => visit_fetch_clause_mut,
+    Cte<'_> => visit_cte_mut,
+    AliasExpr<'_, SelectItem<'_>> => visit_select_alias_mut,
+    AliasExpr<'_, FromItem<'_>> => visit_from_alias_mut,
+    SelectItem<'_> => visit_select_item_mut,
+    FromItem<'_> => visit_from_item_mut,
+    JoinExpr<'_> => visit_join_mut,
+    OrderByItem<'_> => visit_order_by_item_mut,
+    RowCount<'_> => visit_row_count_mut,
+    Expr<'_> => visit_expr_mut,
+    UnaryExpr<'_> => visit_unary_mut,
+    BinaryExpr<'_> => visit_binary_mut,
+    IsNullExpr<'_> => visit_is_null_mut,
+    IsDistinctExpr<'_> => visit_is_distinct_mut,
+    BetweenExpr<'_> => visit_between_mut,
+    InListExpr<'_> => visit_in_list_mut,
+    LikeExpr<'_> => visit_like_mut,
+    AggregateExpr<'_> => visit_aggregate_mut,
+    SubqueryExpr<'_> => visit_subquery_mut,
+    ExistsExpr<'_> => visit_exists_mut,
+    InSubqueryExpr<'_> => visit_in_subquery_mut,
+    QuantifiedExpr<'_> => visit_quantified_mut,
+    NameExpr<'_> => visit_name_mut,
+    IdentExpr<'_> => visit_ident_mut,
+    LiteralExpr<'_> => visit_literal_mut,
+    ParamExpr<'_> => visit_param_mut,
+);
+
+impl<T: ResetSpan> ResetSpan for ItemsExpr<T> {
+    fn reset_span(&mut self) {
+        self.span = Span::default();
+        self.items.reset_span();
+    }
+}
+
+impl ResetSpan for OrderByKey<'_> {
+    fn reset_span(&mut self) {
+        match self {
+            Self::EXPR(expr) => expr.reset_span(),
+            Self::ALIAS(ident) => ident.reset_span(),
+            Self::ORDINAL(_, span) => *span = Span::default(),
+        }
+    }
+}
+
+impl ResetSpan for JoinConstraint<'_> {
+    fn reset_span(&mut self) {
+        match self {
+            Self::ON(expr) => expr.reset_span(),
+            Self::USING(items) => items.reset_span(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::{
+        clause::r#where::WhereClause,
+        expr::{
+            binary::{BinaryExpr, BinaryOp},
+            expr::Expr,
+            ident::IdentExpr,
+            literal::LiteralExpr,
+            name::NameExpr,
+            param::ParamExpr,
+        },
+        frag::from_item::FromItem,
+        parser::parse_stmt,
+        span::{eq_ignore_span, Span},
+        stmt::select::SelectStmt,
+    };
+
+    use super::{walk_from_item, walk_from_item_mut, walk_select_stmt_mut, Visitor, VisitorMut};
+
+    /// Collects the referenced tables
+    struct Tables(Vec<String>);
+
+    impl Visitor for Tables {
+        fn visit_from_item(&mut self, item: &FromItem) {
+            match item {
+                FromItem::NAME(expr) => {
+                    if let Some(table) = expr.last() {
+                        self.0.push(table.value.to_string());
+                    }
+                }
+                _ => walk_from_item(self, item),
+            }
+        }
+    }
+
+    /// Counts all identifiers
+    struct Idents(usize);
+
+    impl Visitor for Idents {
+        fn visit_ident(&mut self, _expr: &IdentExpr) {
+            self.0 += 1;
+        }
+    }
+
+    /// Collects the bind parameters
+    struct Params(Vec<String>);
+
+    impl Visitor for Params {
+        fn visit_param(&mut self, expr: &ParamExpr) {
+            self.0.push(expr.value.to_string());
+        }
+    }
+
+    /// Renames a table
+    struct Rename(&'static str, &'static str);
+
+    impl VisitorMut for Rename {
+        fn visit_from_item_mut(&mut self, item: &mut FromItem) {
+            match item {
+                FromItem::NAME(expr) if expr.last().is_some_and(|table| table.value == self.0) => {
+                    *expr = NameExpr::new(self.1);
+                }
+                _ => walk_from_item_mut(self, item),
+            }
+        }
+    }
+
+    /// Adds `tenant_id = 7` to every select statement
+    struct Tenant;
+
+    impl VisitorMut for Tenant {
+        fn visit_select_stmt_mut(&mut self, stmt: &mut SelectStmt) {
+            walk_select_stmt_mut(self, stmt);
+            let predicate = Expr::BINARY(BinaryExpr::new(
+                Expr::NAME(NameExpr::new("tenant_id")),
+                BinaryOp::EQ,
+                Expr::LITERAL(LiteralExpr::INTEGER("7".into(), Span::default())),
+            ));
+            let expr = match stmt.where_clause.take() {
+                Some(clause) => {
+                    Expr::BINARY(BinaryExpr::new(clause.expr, BinaryOp::AND, predicate))
+                }
+                None => predicate,
+            };
+            stmt.where_clause = Some(WhereClause {
+                expr,
+                span: Span::default(),
+            });
+        }
+    }
+
+    #[test]
+    fn it_visit() {
+        let stmt = parse_stmt(
+            "SELECT a AS x, t1.*, SUM(e) FROM s.t1, t2 AS y WHERE b IN (c, 1) GROUP BY f HAVING MAX(g) > 1 ORDER BY x, d DESC, 1",
+        )
+        .unwrap();
+        let mut tables = Tables(vec![]);
+        tables.visit_stmt(&stmt);
+        assert_eq!(tables.0, ["t1", "t2"]);
+        let mut idents = Idents(0);
+        idents.visit_stmt(&stmt);
+        assert_eq!(idents.0, 14);
+        let stmt = parse_stmt("SELECT DISTINCT ON (a, t.b) c FROM t").unwrap();
+        let mut idents = Idents(0);
+        idents.visit_stmt(&stmt);
+        assert_eq!(idents.0, 5);
+        let stmt =
+            parse_stmt("SELECT a * -b || c FROM t WHERE d NOT LIKE e AND f IS DISTINCT FROM g + 1")
+                .unwrap();
+        let mut idents = Idents(0);
+        idents.visit_stmt(&stmt);
+        assert_eq!(idents.0, 8);
+        let stmt = parse_stmt("SELECT a FROM t1 JOIN (t2 CROSS JOIN t3) ON b = c, t4").unwrap();
+        let mut tables = Tables(vec![]);
+        tables.visit_stmt(&stmt);
+        assert_eq!(tables.0, ["t1", "t2", "t3", "t4"]);
+        let stmt = parse_stmt("WITH x (a) AS (SELECT b FROM t1) SELECT a FROM x, t2").unwrap();
+        let mut tables = Tables(vec![]);
+        tables.visit_stmt(&stmt);
+        assert_eq!(tables.0, ["t1", "x", "t2"]);
+        let stmt = parse_stmt(
+            "WITH x AS (SELECT a FROM t1 UNION SELECT a FROM t2) (SELECT a FROM x) EXCEPT SELECT a FROM t3",
+        )
+        .unwrap();
+        let mut tables = Tables(vec![]);
+        tables.visit_stmt(&stmt);
+        assert_eq!(tables.0, ["t1", "t2", "x", "t3"]);
+        let stmt = parse_stmt(
+            "SELECT (SELECT a FROM t1) FROM (SELECT b FROM t2) AS x WHERE c IN (SELECT c FROM t3) OR d < ALL (SELECT d FROM t4)",
+        )
+        .unwrap();
+        let mut tables = Tables(vec![]);
+        tables.visit_stmt(&stmt);
+        assert_eq!(tables.0, ["t1", "t2", "t3", "t4"]);
+        let stmt =
+            parse_stmt("SELECT a FROM t WHERE b = :b OFFSET $1 FETCH FIRST ? ROWS ONLY").unwrap();
+        let mut params = Params(vec![]);
+        params.visit_stmt(&stmt);
+        assert_eq!(params.0, [":b", "$1", "?"]);
+    }
+
+    #[test]
+    fn it_visit_mut() {
+        let mut stmt = parse_stmt("SELECT a FROM t1, t2 WHERE b = 1 OR c = 2").unwrap();
+        Rename("t2", "t3").visit_stmt_mut(&mut stmt);
+        Tenant.visit_stmt_mut(&mut stmt);
+        assert!(eq_ignore_span(
+            &stmt,
+            &parse_stmt("SELECT a FROM t1, t3 WHERE (b = 1 OR c = 2) AND tenant_id = 7").unwrap()
+        ));
+        let mut stmt = parse_stmt("SELECT a FROM t1 LEFT JOIN t2 USING (b)").unwrap();
+        Rename("t2", "t3").visit_stmt_mut(&mut stmt);
+        assert_eq!(stmt.to_string(), "SELECT a FROM t1 LEFT JOIN t3 USING (b)");
+        let mut stmt = parse_stmt("SELECT a FROM t1").unwrap();
+        Tenant.visit_stmt_mut(&mut stmt);
+        assert_eq!(stmt.to_string(), "SELECT a FROM t1 WHERE tenant_id = 7");
+        let mut stmt = parse_stmt("SELECT a FROM t1 UNION SELECT a FROM t2 WHERE b = 1").unwrap();
+        Tenant.visit_stmt_mut(&mut stmt);
+        assert_eq!(
+            stmt.to_string(),
+            "SELECT a FROM t1 WHERE tenant_id = 7 UNION SELECT a FROM t2 WHERE b = 1 AND tenant_id = 7"
+        );
+    }
+}